@@ -1,54 +1,124 @@
-use core::panic;
-use std::collections::HashMap;
-
+use proc_macro2::Span as Span2;
+use quote::{
+    ToTokens,
+    quote,
+};
 use syn::{
     Attribute,
+    Error,
     Expr,
+    ExprLit,
     Field,
     Fields,
+    GenericArgument,
     Ident,
+    Index,
     Lit,
+    LitBool,
+    LitStr,
+    Member,
     Meta,
     MetaNameValue,
+    PathArguments,
+    Result,
     Token,
+    Type,
     Visibility,
+    ext::IdentExt,
     punctuated::Punctuated,
+    spanned::Spanned,
 };
 
+use super::struct_config::StructConfig;
+
 const CONFIG_ATTRIBUTE: &str = "configure_getter";
+const MUT_CONFIG_ATTRIBUTE: &str = "configure_getter_mut";
 const DISABLE_ATTRIBUTE: &str = "disable_getters";
+const REF_STRATEGY_ATTRIBUTE: &str = "getter_ref_strategy";
 
 const NAME_PARAM: &str = "name";
 const PREFIX_PARAM: &str = "prefix";
 const SUFFIX_PARAM: &str = "suffix";
 const VISIBILITY_PARAM: &str = "visibility";
 const REF_STRATEGY_PARAM: &str = "ref_strategy";
+const DEREF_TARGET_PARAM: &str = "deref_target";
+const CONST_FN_PARAM: &str = "const_fn";
+const DOC_PARAM: &str = "doc";
+
+const MUT_SUFFIX: &str = "mut";
+
+/// Primitive types, which getters with "auto" ref strategy return by
+/// value.
+const PRIMITIVE_TYPES: [&str; 16] = [
+    "bool", "char", "i8", "i16", "i32", "i64", "i128", "isize", "u8", "u16",
+    "u32", "u64", "u128", "usize", "f32", "f64",
+];
 
-const ALLOWED_REF_STRATEGIES: [&str; 2] = ["ref", "none"];
+/// Paths of `Option` type, which are recognised by getters. Other
+/// types named `Option` are not treated as options.
+const OPTION_PATHS: [&[&str]; 3] = [
+    &["Option"],
+    &["std", "option", "Option"],
+    &["core", "option", "Option"],
+];
 
-pub type GetterConfigs<'a> = HashMap<&'a Field, Vec<GetterConfig>>;
+/// Configs of fields in declaration order. Configs of a single field
+/// are kept in order of its attributes, so generated methods are
+/// always emitted in the same order. Fields are paired with members,
+/// which access them, since fields of tuple structs have no names.
+pub type GetterConfigs<'a> = Vec<(Member, &'a Field, Vec<GetterConfig>)>;
 
 #[derive(Debug)]
 pub struct GetterConfig {
     name: String,
     visibility: Visibility,
     ref_strategy: GetterRefStrategy,
+    deref_target: Option<Type>,
+    mutable: bool,
+    const_fn: bool,
+    doc: Option<String>,
 }
 
-#[derive(Debug, Clone, Copy)]
+#[derive(Debug, Clone, Copy, PartialEq)]
 pub enum GetterRefStrategy {
+    Auto,
     Ref,
     None,
+    Copy,
+    Clone,
+    Deref,
+    AsDeref,
+    AsMut,
+}
+
+/// Raw values of `configure_getter` attribute params. Literals are
+/// kept as is, so errors can point at them.
+#[derive(Debug, Default)]
+struct RawGetterParams {
+    name: Option<LitStr>,
+    prefix: Option<LitStr>,
+    suffix: Option<LitStr>,
+    visibility: Option<LitStr>,
+    ref_strategy: Option<LitStr>,
+    deref_target: Option<LitStr>,
+    const_fn: Option<LitBool>,
+    doc: Option<LitStr>,
 }
 
 impl TryFrom<String> for GetterRefStrategy {
     type Error = String;
 
-    fn try_from(value: String) -> Result<Self, Self::Error> {
+    fn try_from(value: String) -> std::result::Result<Self, Self::Error> {
         match value.as_str() {
+            "auto" => Ok(Self::Auto),
             "ref" => Ok(Self::Ref),
             "none" => Ok(Self::None),
-            _ => Err(format!("Invalid visibility: '{value}'.")),
+            "copy" => Ok(Self::Copy),
+            "clone" => Ok(Self::Clone),
+            "deref" => Ok(Self::Deref),
+            "as_deref" => Ok(Self::AsDeref),
+            "as_mut" => Ok(Self::AsMut),
+            _ => Err(format!("Invalid ref strategy: '{value}'.")),
         }
     }
 }
@@ -65,19 +135,45 @@ impl GetterConfig {
     pub fn ref_strategy(&self) -> GetterRefStrategy {
         self.ref_strategy
     }
+
+    pub fn deref_target(&self) -> Option<&Type> {
+        self.deref_target.as_ref()
+    }
+
+    pub fn mutable(&self) -> bool {
+        self.mutable
+    }
+
+    pub fn const_fn(&self) -> bool {
+        self.const_fn
+    }
+
+    pub fn doc(&self) -> Option<&str> {
+        self.doc.as_deref()
+    }
 }
 
-/// Extracts configs from config attributes of field and returns
-/// them. If field has no attribute, this function returns Vec
-/// with a default config.
-pub fn make_getter_configs(fields: &mut Fields) -> GetterConfigs {
+/// Extracts configs of every field and removes config attributes
+/// from fields. Errors of all fields are combined, so they are
+/// reported together.
+pub fn make_getter_configs<'a>(
+    fields: &'a mut Fields,
+    struct_config: &StructConfig,
+) -> Result<GetterConfigs<'a>> {
     let fields = match fields {
         Fields::Named(fields) => &mut fields.named,
-        _ => panic!("Macro supports only structs with named fields."),
+        Fields::Unnamed(fields) => &mut fields.unnamed,
+        Fields::Unit => return Ok(GetterConfigs::new()),
     };
-    let mut getter_configs: GetterConfigs = Vec::new();
+    let mut getter_configs: GetterConfigs = GetterConfigs::new();
+    let mut method_names: Vec<String> = Vec::new();
+    let mut errors: Vec<Error> = Vec::new();
 
-    for field in fields {
+    for (index, field) in fields.iter_mut().enumerate() {
+        let member = match &field.ident {
+            Some(field_ident) => Member::Named(field_ident.clone()),
+            None => Member::Unnamed(Index::from(index)),
+        };
         let is_disabled = field
             .attrs
             .iter()
@@ -88,68 +184,588 @@ pub fn make_getter_configs(fields: &mut Fields) -> GetterConfigs {
             continue;
         }
 
-        let name = extract_name(field).unwrap_or_else(|| {
-            let prefix = extract_prefix(field);
-            let suffix = extract_suffix(field);
-
-            if prefix.is_some() && suffix.is_none() {
-                return format!(
-                    "{0}_{1}",
-                    prefix.unwrap(),
-                    field.ident.as_ref().unwrap()
-                );
-            } else if prefix.is_none() && suffix.is_some() {
-                panic!(
-                    "'{0}' attribute must be used with '{1}' attribute.",
-                    SUFFIX_ATTRIBUTE, PREFIX_ATTRIBUTE,
-                )
-            } else if prefix.is_none() && suffix.is_none() {
-                return field.ident.as_ref().unwrap().to_string();
+        let field_getter_configs =
+            extract_configs(field, &member, struct_config, &mut method_names);
+        remove_attributes(field);
+
+        match field_getter_configs {
+            Ok(field_getter_configs) => {
+                getter_configs.push((member, field, field_getter_configs))
             }
+            Err(error) => errors.push(error),
+        }
+    }
+
+    combine_errors(errors)?;
+    Ok(getter_configs)
+}
+
+/// Extracts configs from config attributes of field and returns
+/// them. If field has no `configure_getter` attribute, this function
+/// returns Vec with a default config followed by configs of
+/// `configure_getter_mut` attributes. Unnamed fields get no default
+/// config, since their getters must be named explicitly. Names of
+/// getters are added to `method_names` of previous getters.
+fn extract_configs(
+    field: &Field,
+    member: &Member,
+    struct_config: &StructConfig,
+    method_names: &mut Vec<String>,
+) -> Result<Vec<GetterConfig>> {
+    let default_ref_strategy = extract_ref_strategy_shorthand(field)?;
+
+    let attributes: Vec<&Attribute> = field
+        .attrs
+        .iter()
+        .filter(|attr| {
+            let path = attr.path();
+            path.is_ident(CONFIG_ATTRIBUTE)
+                || path.is_ident(MUT_CONFIG_ATTRIBUTE)
+        })
+        .collect();
 
-            format!("{0}_{1}", prefix.unwrap(), suffix.unwrap())
+    let mut getter_configs: Vec<GetterConfig> = Vec::new();
+    let mut errors: Vec<Error> = Vec::new();
+
+    let has_config = attributes
+        .iter()
+        .any(|attr| attr.path().is_ident(CONFIG_ATTRIBUTE));
+    if let (false, Member::Named(field_ident)) = (has_config, member) {
+        let name = field_ident.to_string();
+        let visibility = default_visibility_factory();
+        let const_fn = struct_config.const_fn();
+        let ref_strategy = make_ref_strategy(
+            default_ref_strategy.as_ref(),
+            GetterRefStrategy::Auto,
+        )
+        .and_then(|ref_strategy| {
+            check_const_fn(
+                ref_strategy,
+                const_fn,
+                default_ref_strategy.as_ref(),
+            )?;
+            Ok(ref_strategy)
         });
+        let checked_name = check_method_name(&name, field_ident, method_names);
 
-        let visibility = extract_visibility(field)
-            .map(|v| v.try_into().unwrap_or_else(|e| panic!("{}", e)))
-            .unwrap_or(GetterVisibility::Pub);
-        let ref_strategy = extract_ref_strategy(field)
-            .map(|v| v.try_into().unwrap_or_else(|e| panic!("{}", e)))
-            .unwrap_or(GetterRefStrategy::None);
+        match (ref_strategy, checked_name) {
+            (Ok(ref_strategy), Ok(())) => {
+                method_names.push(name.clone());
+                getter_configs.push(GetterConfig {
+                    name,
+                    visibility,
+                    ref_strategy,
+                    deref_target: None,
+                    mutable: false,
+                    const_fn,
+                    doc: None,
+                });
+            }
+            (Err(error), _) | (_, Err(error)) => errors.push(error),
+        }
+    }
 
-        remove_attributes(field);
+    for attribute in attributes {
+        let getter_config = extract_config(
+            member,
+            attribute,
+            default_ref_strategy.as_ref(),
+            struct_config.const_fn(),
+        )
+        .and_then(|getter_config| {
+            check_method_name(&getter_config.name, attribute, method_names)?;
+            Ok(getter_config)
+        });
+        match getter_config {
+            Ok(getter_config) => {
+                method_names.push(getter_config.name.clone());
+                getter_configs.push(getter_config);
+            }
+            Err(error) => errors.push(error),
+        }
+    }
+    combine_errors(errors)?;
 
-        let getter_config = GetterConfig {
-            field,
-            name,
-            visibility,
-            ref_strategy,
-        };
-        getter_configs.push(getter_config);
+    for getter_config in getter_configs.iter_mut() {
+        if getter_config.ref_strategy == GetterRefStrategy::Auto {
+            getter_config.ref_strategy =
+                if is_by_value_type(&field.ty, struct_config.copy_types()) {
+                    GetterRefStrategy::Copy
+                } else {
+                    GetterRefStrategy::Ref
+                };
+        }
+    }
+
+    Ok(getter_configs)
+}
+
+/// Checks that getter is not named after a method made by a previous
+/// getter of the struct, e.g. mutable getter of `foo` field and
+/// getter of `foo_mut` field are both named `foo_mut`.
+fn check_method_name(
+    name: &str,
+    tokens: impl ToTokens,
+    method_names: &[String],
+) -> Result<()> {
+    if !method_names.iter().any(|method_name| method_name == name) {
+        return Ok(());
+    }
+
+    Err(Error::new_spanned(
+        tokens,
+        format!(
+            "Method `{name}` is already made by another getter. Set \
+            '{NAME_PARAM}', '{PREFIX_PARAM}' or '{SUFFIX_PARAM}' param \
+            to rename the getter."
+        ),
+    ))
+}
+
+/// Checks whether getters with "auto" ref strategy should return
+/// value of the type instead of reference to it. Since trait impls
+/// cannot be seen by the macro, only primitives, shared references,
+/// pointers, tuples, arrays and options of such types and types from
+/// `copy_types` macro param are returned by value.
+fn is_by_value_type(field_type: &Type, copy_types: &[Type]) -> bool {
+    let is_copy_type = copy_types.iter().any(|copy_type| {
+        quote!(#copy_type).to_string() == quote!(#field_type).to_string()
+    });
+    if is_copy_type {
+        return true;
+    }
+
+    match field_type {
+        Type::Reference(reference) => reference.mutability.is_none(),
+        Type::Ptr(_) | Type::BareFn(_) | Type::Never(_) => true,
+        Type::Paren(paren) => is_by_value_type(&paren.elem, copy_types),
+        Type::Group(group) => is_by_value_type(&group.elem, copy_types),
+        Type::Array(array) => is_by_value_type(&array.elem, copy_types),
+        Type::Tuple(tuple) => tuple
+            .elems
+            .iter()
+            .all(|elem| is_by_value_type(elem, copy_types)),
+        Type::Path(type_path) if type_path.qself.is_none() => {
+            if let Some(ident) = type_path.path.get_ident() {
+                return PRIMITIVE_TYPES.contains(&ident.to_string().as_str());
+            }
+
+            extract_option_inner_type(field_type).is_some_and(|inner_type| {
+                is_by_value_type(inner_type, copy_types)
+            })
+        }
+        _ => false,
     }
+}
 
-    getter_configs
+pub fn extract_option_inner_type(field_type: &Type) -> Option<&Type> {
+    if has_path(field_type, &OPTION_PATHS) {
+        return extract_first_type_argument(field_type);
+    }
+    None
 }
 
-fn extact_config(field_ident: &Ident, attribute: &Attribute) -> GetterConfig {
-    let name_values: Punctuated<MetaNameValue, Token![,]> = attribute
-        .parse_args_with(Punctuated::parse_terminated)
-        .unwrap();
+pub fn has_path(field_type: &Type, paths: &[&[&str]]) -> bool {
+    let Type::Path(type_path) = field_type else {
+        return false;
+    };
+    if type_path.qself.is_some() {
+        return false;
+    }
 
-    let mut name: Option<String> = None;
-    let mut prefix: Option<String> = None;
-    let mut suffix: Option<String> = None;
-    let mut raw_visibility: Option<String> = None;
-    let mut raw_ref_strategy: Option<String> = None;
+    let path = &type_path.path;
+    let segments: Vec<String> = path
+        .segments
+        .iter()
+        .map(|segment| segment.ident.to_string())
+        .collect();
+    // `::Option` refers to a crate named `Option`, not the type.
+    paths.iter().any(|known_path| {
+        *known_path == segments.as_slice()
+            && (path.leading_colon.is_none() || known_path.len() > 1)
+    })
+}
+
+pub fn extract_first_type_argument(field_type: &Type) -> Option<&Type> {
+    let Type::Path(type_path) = field_type else {
+        return None;
+    };
+    let PathArguments::AngleBracketed(args) =
+        &type_path.path.segments.last()?.arguments
+    else {
+        return None;
+    };
+
+    match args.args.first() {
+        Some(GenericArgument::Type(inner_type)) => Some(inner_type),
+        _ => None,
+    }
+}
+
+fn extract_config(
+    member: &Member,
+    attribute: &Attribute,
+    default_ref_strategy: Option<&LitStr>,
+    default_const_fn: bool,
+) -> Result<GetterConfig> {
+    let mutable = attribute.path().is_ident(MUT_CONFIG_ATTRIBUTE);
+    let name_values: Punctuated<MetaNameValue, Token![,]> =
+        match &attribute.meta {
+            Meta::Path(_) => Punctuated::new(),
+            _ => attribute.parse_args_with(Punctuated::parse_terminated)?,
+        };
+
+    let mut params = RawGetterParams::default();
+    let mut errors: Vec<Error> = Vec::new();
 
     for name_value in name_values {
-        if let Expr::Lit(lit_expr) = name_value.value {}
+        if let Err(error) = parse_attribute_param(name_value, &mut params) {
+            errors.push(error);
+        }
     }
+    combine_errors(errors)?;
+
+    let name = make_name(&params, member, mutable, attribute);
+    let visibility = match params.visibility.as_ref() {
+        Some(visibility) => visibility.parse(),
+        None => Ok(default_visibility_factory()),
+    };
+    let const_fn = params
+        .const_fn
+        .as_ref()
+        .map_or(default_const_fn, LitBool::value);
+    let raw_ref_strategy = if mutable {
+        params.ref_strategy.as_ref()
+    } else {
+        params.ref_strategy.as_ref().or(default_ref_strategy)
+    };
+    let ref_strategy = make_config_ref_strategy(raw_ref_strategy, mutable)
+        .and_then(|ref_strategy| {
+            check_const_fn(ref_strategy, const_fn, raw_ref_strategy)?;
+            Ok(ref_strategy)
+        });
+    let deref_target = match (params.deref_target.as_ref(), &ref_strategy) {
+        (None, _) => Ok(None),
+        (
+            Some(deref_target),
+            Ok(GetterRefStrategy::Deref | GetterRefStrategy::AsDeref),
+        ) => deref_target.parse().map(Some),
+        (Some(deref_target), Ok(_)) => Err(Error::new(
+            deref_target.span(),
+            format!(
+                "'{DEREF_TARGET_PARAM}' param can be set only with \
+                \"deref\" and \"as_deref\" ref strategies."
+            ),
+        )),
+        // Ref strategy is invalid, so there is nothing to check.
+        (Some(_), Err(_)) => Ok(None),
+    };
+
+    let errors = [
+        name.as_ref().err(),
+        visibility.as_ref().err(),
+        ref_strategy.as_ref().err(),
+        deref_target.as_ref().err(),
+    ];
+    combine_errors(errors.into_iter().flatten().cloned().collect())?;
+
+    Ok(GetterConfig {
+        name: name?,
+        visibility: visibility?,
+        ref_strategy: ref_strategy?,
+        deref_target: deref_target?,
+        mutable,
+        const_fn,
+        doc: params.doc.as_ref().map(LitStr::value),
+    })
+}
+
+/// Extracts value of `#[getter_ref_strategy = "..."]` attribute,
+/// which sets `ref_strategy` param for every getter of the field
+/// that doesn't set it explicitly.
+fn extract_ref_strategy_shorthand(field: &Field) -> Result<Option<LitStr>> {
+    let Some(attribute) = field
+        .attrs
+        .iter()
+        .find(|attr| attr.path().is_ident(REF_STRATEGY_ATTRIBUTE))
+    else {
+        return Ok(None);
+    };
+
+    if let Meta::NameValue(name_value) = &attribute.meta {
+        if let Expr::Lit(ExprLit {
+            lit: Lit::Str(value),
+            ..
+        }) = &name_value.value
+        {
+            return Ok(Some(value.clone()));
+        }
+    }
+    Err(Error::new_spanned(
+        attribute,
+        format!(
+            "'{REF_STRATEGY_ATTRIBUTE}' attribute expects a string value."
+        ),
+    ))
+}
+
+fn parse_attribute_param(
+    name_value: MetaNameValue,
+    params: &mut RawGetterParams,
+) -> Result<()> {
+    let Some(param_ident) = name_value.path.get_ident() else {
+        return Err(Error::new_spanned(&name_value.path, "Unexpected param."));
+    };
+    let Expr::Lit(ExprLit { lit, .. }) = name_value.value else {
+        return Err(Error::new_spanned(
+            &name_value.value,
+            "Expected literal value.",
+        ));
+    };
+
+    match (param_ident.to_string().as_str(), lit) {
+        (NAME_PARAM, Lit::Str(value)) => {
+            set_param(&mut params.name, value, param_ident)
+        }
+        (PREFIX_PARAM, Lit::Str(value)) => {
+            set_param(&mut params.prefix, value, param_ident)
+        }
+        (SUFFIX_PARAM, Lit::Str(value)) => {
+            set_param(&mut params.suffix, value, param_ident)
+        }
+        (VISIBILITY_PARAM, Lit::Str(value)) => {
+            set_param(&mut params.visibility, value, param_ident)
+        }
+        (REF_STRATEGY_PARAM, Lit::Str(value)) => {
+            set_param(&mut params.ref_strategy, value, param_ident)
+        }
+        (DEREF_TARGET_PARAM, Lit::Str(value)) => {
+            set_param(&mut params.deref_target, value, param_ident)
+        }
+        (DOC_PARAM, Lit::Str(value)) => {
+            set_param(&mut params.doc, value, param_ident)
+        }
+        (CONST_FN_PARAM, Lit::Bool(value)) => {
+            set_param(&mut params.const_fn, value, param_ident)
+        }
+        (
+            NAME_PARAM | PREFIX_PARAM | SUFFIX_PARAM | VISIBILITY_PARAM
+            | REF_STRATEGY_PARAM | DEREF_TARGET_PARAM | DOC_PARAM,
+            lit,
+        ) => Err(Error::new_spanned(lit, "Expected string value.")),
+        (CONST_FN_PARAM, lit) => {
+            Err(Error::new_spanned(lit, "Expected bool value."))
+        }
+        _ => Err(Error::new_spanned(
+            param_ident,
+            format!("Unexpected param '{param_ident}'."),
+        )),
+    }
+}
+
+pub(crate) fn set_param<T>(
+    param: &mut Option<T>,
+    value: T,
+    param_ident: &Ident,
+) -> Result<()> {
+    if param.is_some() {
+        return Err(Error::new_spanned(
+            param_ident,
+            format!("Duplicate '{param_ident}' param."),
+        ));
+    }
+    *param = Some(value);
+    Ok(())
+}
+
+/// Makes name of getter. Getters of unnamed fields have no default
+/// name, so it must be set by params of the attribute.
+fn make_name(
+    params: &RawGetterParams,
+    member: &Member,
+    mutable: bool,
+    attribute: &Attribute,
+) -> Result<String> {
+    let field_name = match member {
+        Member::Named(field_ident) => field_ident.unraw().to_string(),
+        Member::Unnamed(index) => index.index.to_string(),
+    };
+    let default_suffix = if mutable {
+        format!("{field_name}_{MUT_SUFFIX}")
+    } else {
+        field_name
+    };
+
+    let name = match (&params.name, &params.prefix, &params.suffix) {
+        (None, Some(prefix), Some(suffix)) => {
+            format!("{}_{}", prefix.value(), suffix.value())
+        }
+        (None, Some(prefix), None) => {
+            format!("{}_{default_suffix}", prefix.value())
+        }
+        (None, None, Some(suffix)) => suffix.value(),
+        (None, None, None) if matches!(member, Member::Unnamed(_)) => {
+            return Err(Error::new_spanned(
+                attribute,
+                format!(
+                    "Getter of unnamed field requires '{NAME_PARAM}', \
+                    '{PREFIX_PARAM}' or '{SUFFIX_PARAM}' param."
+                ),
+            ));
+        }
+        // Getter of raw field keeps its raw name, e.g. `r#type`.
+        (None, None, None) if !mutable => quote!(#member).to_string(),
+        (None, None, None) => default_suffix,
+        (Some(name), Some(_), Some(_)) => {
+            return Err(Error::new(
+                name.span(),
+                format!(
+                    "'{NAME_PARAM}' param cannot be set with \
+                    '{PREFIX_PARAM}' and '{SUFFIX_PARAM}' params."
+                ),
+            ));
+        }
+        (Some(name), Some(_), None) => {
+            return Err(Error::new(
+                name.span(),
+                format!(
+                    "'{NAME_PARAM}' param cannot be set with \
+                    '{PREFIX_PARAM}' param."
+                ),
+            ));
+        }
+        (Some(name), None, Some(_)) => {
+            return Err(Error::new(
+                name.span(),
+                format!(
+                    "'{NAME_PARAM}' param cannot be set with \
+                    '{SUFFIX_PARAM}' param."
+                ),
+            ));
+        }
+        (Some(name), None, None) => name.value(),
+    };
+
+    if syn::parse_str::<Ident>(&name).is_err() {
+        let span = [&params.name, &params.prefix, &params.suffix]
+            .into_iter()
+            .flatten()
+            .map(LitStr::span)
+            .next()
+            .unwrap_or_else(|| attribute.span());
+        return Err(Error::new(
+            span,
+            format!("'{name}' is not a valid method name."),
+        ));
+    }
+
+    Ok(name)
+}
+
+fn make_ref_strategy(
+    raw_ref_strategy: Option<&LitStr>,
+    default_ref_strategy: GetterRefStrategy,
+) -> Result<GetterRefStrategy> {
+    match raw_ref_strategy {
+        Some(raw_ref_strategy) => {
+            raw_ref_strategy
+                .value()
+                .try_into()
+                .map_err(|error: String| {
+                    Error::new(raw_ref_strategy.span(), error)
+                })
+        }
+        None => Ok(default_ref_strategy),
+    }
+}
+
+/// Makes ref strategy of getter configured by attribute and checks
+/// that it can be used with the attribute.
+fn make_config_ref_strategy(
+    raw_ref_strategy: Option<&LitStr>,
+    mutable: bool,
+) -> Result<GetterRefStrategy> {
+    let default_ref_strategy = if mutable {
+        GetterRefStrategy::Ref
+    } else {
+        GetterRefStrategy::Auto
+    };
+    let ref_strategy =
+        make_ref_strategy(raw_ref_strategy, default_ref_strategy)?;
+    let span = raw_ref_strategy.map_or_else(Span2::call_site, LitStr::span);
+
+    match ref_strategy {
+        GetterRefStrategy::Ref | GetterRefStrategy::AsMut if mutable => {
+            Ok(ref_strategy)
+        }
+        _ if mutable => Err(Error::new(
+            span,
+            format!(
+                "'{MUT_CONFIG_ATTRIBUTE}' attribute supports only \
+                \"ref\" and \"as_mut\" ref strategies."
+            ),
+        )),
+        GetterRefStrategy::AsMut => Err(Error::new(
+            span,
+            format!(
+                "\"as_mut\" ref strategy can be used only with \
+                '{MUT_CONFIG_ATTRIBUTE}' attribute."
+            ),
+        )),
+        _ => Ok(ref_strategy),
+    }
+}
+
+/// Checks that getter with the ref strategy can be const. Error
+/// points at the ref strategy, since it is the one to be changed.
+fn check_const_fn(
+    ref_strategy: GetterRefStrategy,
+    const_fn: bool,
+    raw_ref_strategy: Option<&LitStr>,
+) -> Result<()> {
+    let is_const_incompatible = matches!(
+        ref_strategy,
+        GetterRefStrategy::Clone
+            | GetterRefStrategy::Deref
+            | GetterRefStrategy::AsDeref
+    );
+    if !(const_fn && is_const_incompatible) {
+        return Ok(());
+    }
+
+    let span = raw_ref_strategy.map_or_else(Span2::call_site, LitStr::span);
+    Err(Error::new(
+        span,
+        "Getter cannot be const, because \"clone\", \"deref\" and \
+        \"as_deref\" ref strategies call non-const trait methods.",
+    ))
+}
+
+fn default_visibility_factory() -> Visibility {
+    let span = Span2::call_site();
+    let pub_token = Token![pub](span);
+    Visibility::Public(pub_token)
 }
 
 fn remove_attributes(field: &mut Field) {
     field.attrs.retain(|attr| {
         let path = attr.path();
-        !(path.is_ident(DISABLE_ATTRIBUTE) || path.is_ident(CONFIG_ATTRIBUTE))
+        !(path.is_ident(DISABLE_ATTRIBUTE)
+            || path.is_ident(CONFIG_ATTRIBUTE)
+            || path.is_ident(MUT_CONFIG_ATTRIBUTE)
+            || path.is_ident(REF_STRATEGY_ATTRIBUTE))
+    });
+}
+
+/// Combines errors into one, so all of them are reported together.
+pub(crate) fn combine_errors(errors: Vec<Error>) -> Result<()> {
+    let combined = errors.into_iter().reduce(|mut combined, error| {
+        combined.combine(error);
+        combined
     });
+
+    match combined {
+        Some(error) => Err(error),
+        None => Ok(()),
+    }
 }
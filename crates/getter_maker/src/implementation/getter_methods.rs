@@ -2,46 +2,274 @@ use proc_macro2::{
     Span as Span2,
     TokenStream as TokenStream2,
 };
-use quote::quote;
-use syn::Ident;
+use quote::{
+    format_ident,
+    quote,
+    quote_spanned,
+};
+use syn::{
+    Attribute,
+    Error,
+    Field,
+    Member,
+    Result,
+    Type,
+    spanned::Spanned,
+};
 
 use super::getter_configs::{
-    GetterConfig,
+    GetterConfigs,
     GetterRefStrategy,
-    GetterVisibility,
+    combine_errors,
+    extract_first_type_argument,
+    extract_option_inner_type,
+    has_path,
 };
 
+const DOC_ATTRIBUTE: &str = "doc";
+
+const STRING_PATHS: [&[&str]; 3] = [
+    &["String"],
+    &["std", "string", "String"],
+    &["alloc", "string", "String"],
+];
+const PATH_BUF_PATHS: [&[&str]; 2] =
+    [&["PathBuf"], &["std", "path", "PathBuf"]];
+const OS_STRING_PATHS: [&[&str]; 2] =
+    [&["OsString"], &["std", "ffi", "OsString"]];
+const C_STRING_PATHS: [&[&str]; 3] = [
+    &["CString"],
+    &["std", "ffi", "CString"],
+    &["alloc", "ffi", "CString"],
+];
+const VEC_PATHS: [&[&str]; 3] =
+    [&["Vec"], &["std", "vec", "Vec"], &["alloc", "vec", "Vec"]];
+/// Paths of smart pointers, which deref to their first type argument.
+const POINTER_PATHS: [&[&str]; 9] = [
+    &["Box"],
+    &["std", "boxed", "Box"],
+    &["alloc", "boxed", "Box"],
+    &["Rc"],
+    &["std", "rc", "Rc"],
+    &["alloc", "rc", "Rc"],
+    &["Arc"],
+    &["std", "sync", "Arc"],
+    &["alloc", "sync", "Arc"],
+];
+
+/// Makes getter methods from configs. Errors of all getters, which
+/// cannot be made for types of their fields, are reported together.
 pub fn make_getter_methods(
-    getter_configs: Vec<GetterConfig>,
-) -> Vec<TokenStream2> {
+    getter_configs: GetterConfigs,
+) -> Result<Vec<TokenStream2>> {
     let mut getter_methods: Vec<TokenStream2> = Vec::new();
+    let mut errors: Vec<Error> = Vec::new();
 
-    for getter_config in getter_configs {
-        let field = getter_config.field();
-
-        let field_name = &field.ident;
+    for (member, field, field_getter_configs) in getter_configs.iter() {
         let field_type = &field.ty;
+        let field_name = match member {
+            Member::Named(field_ident) => field_ident.to_string(),
+            Member::Unnamed(index) => index.index.to_string(),
+        };
+        let span = Span2::call_site();
 
-        let method_name = Ident::new(getter_config.name(), Span2::call_site());
+        for field_getter_config in field_getter_configs {
+            let method_name =
+                format_ident!("{}", field_getter_config.name(), span = span);
+            let method_visibility = field_getter_config.visibility();
 
-        let method_visibility = match getter_config.visibility() {
-            GetterVisibility::Pub => Some(quote! { pub }),
-            GetterVisibility::PubForCrate => Some(quote! { pub(crate) }),
-            GetterVisibility::Private => None,
-        };
-        let reference = match getter_config.ref_strategy() {
-            GetterRefStrategy::Ref => Some(quote!( & )),
-            GetterRefStrategy::None => None,
-            _ => unreachable!(),
-        };
+            // Trait bounds are checked by helper functions spanned to
+            // the field type, so unsupported types are reported there.
+            let (return_type, body) = match field_getter_config.ref_strategy()
+            {
+                GetterRefStrategy::Auto => unreachable!(),
+                GetterRefStrategy::Ref if field_getter_config.mutable() => {
+                    (quote! { &mut #field_type }, quote! { &mut self.#member })
+                }
+                GetterRefStrategy::Ref => {
+                    (quote! { &#field_type }, quote! { &self.#member })
+                }
+                GetterRefStrategy::None => {
+                    (quote! { #field_type }, quote! { self.#member })
+                }
+                GetterRefStrategy::Copy => (
+                    quote! { #field_type },
+                    quote_spanned! { field_type.span() =>
+                        {
+                            const fn copy<T: ::core::marker::Copy>(value: &T) -> T {
+                                *value
+                            }
+                            copy(&self.#member)
+                        }
+                    },
+                ),
+                GetterRefStrategy::Clone => (
+                    quote! { #field_type },
+                    quote_spanned! { field_type.span() =>
+                        ::core::clone::Clone::clone(&self.#member)
+                    },
+                ),
+                GetterRefStrategy::Deref => {
+                    match make_deref_target(
+                        field_type,
+                        field_getter_config.deref_target(),
+                    ) {
+                        Ok(target) => {
+                            (quote! { &#target }, quote! { &self.#member })
+                        }
+                        Err(error) => {
+                            errors.push(error);
+                            continue;
+                        }
+                    }
+                }
+                GetterRefStrategy::AsDeref => {
+                    let target = extract_option_inner_type(field_type)
+                        .ok_or_else(|| {
+                            Error::new_spanned(
+                                field_type,
+                                "\"as_deref\" ref strategy can be used \
+                                only with Option fields.",
+                            )
+                        })
+                        .and_then(|inner_type| {
+                            make_deref_target(
+                                inner_type,
+                                field_getter_config.deref_target(),
+                            )
+                        });
+                    match target {
+                        Ok(target) => (
+                            quote! { ::core::option::Option<&#target> },
+                            quote! { self.#member.as_deref() },
+                        ),
+                        Err(error) => {
+                            errors.push(error);
+                            continue;
+                        }
+                    }
+                }
+                GetterRefStrategy::AsMut => {
+                    let Some(inner_type) =
+                        extract_option_inner_type(field_type)
+                    else {
+                        errors.push(Error::new_spanned(
+                            field_type,
+                            "\"as_mut\" ref strategy can be used only \
+                            with Option fields.",
+                        ));
+                        continue;
+                    };
+                    (
+                        quote! { ::core::option::Option<&mut #inner_type> },
+                        quote! { self.#member.as_mut() },
+                    )
+                }
+            };
+            let constness = field_getter_config.const_fn().then(|| {
+                quote! { const }
+            });
+            let lead = match field_getter_config.ref_strategy() {
+                _ if field_getter_config.mutable() => {
+                    format!("Returns a mutable reference to `{field_name}`.")
+                }
+                GetterRefStrategy::None | GetterRefStrategy::Copy => {
+                    format!("Returns the value of `{field_name}`.")
+                }
+                GetterRefStrategy::Clone => {
+                    format!("Returns a clone of `{field_name}`.")
+                }
+                _ => format!("Returns a reference to `{field_name}`."),
+            };
+            let docs = make_docs(lead, field, field_getter_config.doc());
+            let receiver = if field_getter_config.mutable() {
+                quote! { &mut self }
+            } else {
+                quote! { &self }
+            };
 
-        let getter_method = quote! {
-            #method_visibility fn #method_name(&self) -> #reference #field_type {
-                #reference self.#field_name
-            }
-        };
-        getter_methods.push(getter_method);
+            let getter_method = quote! {
+                #docs
+                #method_visibility #constness fn #method_name(
+                    #receiver,
+                ) -> #return_type {
+                    #body
+                }
+            };
+            getter_methods.push(getter_method);
+        }
+    }
+
+    combine_errors(errors)?;
+    Ok(getter_methods)
+}
+
+/// Makes doc attributes of method, which consist of generated lead
+/// line followed by docs of field, unless docs are set explicitly.
+fn make_docs(lead: String, field: &Field, doc: Option<&str>) -> TokenStream2 {
+    if let Some(doc) = doc {
+        return quote! { #[doc = #doc] };
+    }
+
+    let lead = format!(" {lead}");
+    let field_docs: Vec<&Attribute> = field
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident(DOC_ATTRIBUTE))
+        .collect();
+
+    if field_docs.is_empty() {
+        return quote! { #[doc = #lead] };
+    }
+    quote! {
+        #[doc = #lead]
+        #[doc = ""]
+        #(#field_docs)*
     }
+}
+
+/// Returns the explicitly configured deref target or infers it for
+/// the standard owning types.
+fn make_deref_target(
+    field_type: &Type,
+    deref_target: Option<&Type>,
+) -> Result<TokenStream2> {
+    if let Some(deref_target) = deref_target {
+        return Ok(quote! { #deref_target });
+    }
+
+    infer_deref_target(field_type).ok_or_else(|| {
+        Error::new_spanned(
+            field_type,
+            format!(
+                "Cannot infer deref target of '{}' type, \
+                set 'deref_target' param.",
+                quote! { #field_type }
+            ),
+        )
+    })
+}
 
-    getter_methods
+/// Infers deref target of standard owning types, which are recognised
+/// by their paths like `Option` is. Other types with the same names
+/// have no inferred target.
+fn infer_deref_target(field_type: &Type) -> Option<TokenStream2> {
+    let deref_target = if has_path(field_type, &STRING_PATHS) {
+        quote! { str }
+    } else if has_path(field_type, &PATH_BUF_PATHS) {
+        quote! { ::std::path::Path }
+    } else if has_path(field_type, &OS_STRING_PATHS) {
+        quote! { ::std::ffi::OsStr }
+    } else if has_path(field_type, &C_STRING_PATHS) {
+        quote! { ::core::ffi::CStr }
+    } else if has_path(field_type, &VEC_PATHS) {
+        let inner_type = extract_first_type_argument(field_type)?;
+        quote! { [#inner_type] }
+    } else if has_path(field_type, &POINTER_PATHS) {
+        let inner_type = extract_first_type_argument(field_type)?;
+        quote! { #inner_type }
+    } else {
+        return None;
+    };
+    Some(deref_target)
 }
@@ -1,22 +1,112 @@
 use proc_macro::TokenStream as TokenStream1;
+use proc_macro2::TokenStream as TokenStream2;
 use quote::quote;
-use syn::ItemStruct;
+use syn::{
+    Error,
+    ItemStruct,
+    MetaNameValue,
+    Token,
+    punctuated::Punctuated,
+};
 
 use super::{
     getter_configs::make_getter_configs,
     getter_methods::make_getter_methods,
     impl_block::make_impl_block,
+    struct_config::{
+        StructConfig,
+        make_struct_config,
+    },
 };
 
-pub fn do_make_getters(mut input: ItemStruct) -> TokenStream1 {
-    let getter_configs = make_getter_configs(&mut input.fields);
-    let getter_methods = make_getter_methods(getter_configs);
+pub fn do_make_getters(
+    params: Punctuated<MetaNameValue, Token![,]>,
+    input: ItemStruct,
+) -> TokenStream1 {
+    make_getters(params, input).into()
+}
+
+fn make_getters(
+    params: Punctuated<MetaNameValue, Token![,]>,
+    mut input: ItemStruct,
+) -> TokenStream2 {
+    let mut errors: Vec<Error> = Vec::new();
+
+    // Config attributes must be removed from fields even if params
+    // of the macro are invalid, so the default config is used then.
+    let struct_config = make_struct_config(params).unwrap_or_else(|error| {
+        errors.push(error);
+        StructConfig::default()
+    });
     let impl_block =
-        make_impl_block(&input.ident, &input.generics, getter_methods);
+        match make_getter_configs(&mut input.fields, &struct_config)
+            .and_then(make_getter_methods)
+        {
+            Ok(getter_methods) => {
+                make_impl_block(&input.ident, &input.generics, getter_methods)
+            }
+            Err(error) => {
+                errors.push(error);
+                TokenStream2::new()
+            }
+        };
+    let errors = errors.iter().map(Error::to_compile_error);
 
     quote! {
         #input
         #impl_block
+        #(#errors)*
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proc_macro2::TokenStream as TokenStream2;
+    use syn::{
+        File,
+        ImplItem,
+        Item,
+        ItemStruct,
+        parse_quote,
+        punctuated::Punctuated,
+    };
+
+    use super::make_getters;
+
+    /// Returns names of methods of impl blocks in order of expansion.
+    fn collect_method_names(expansion: TokenStream2) -> Vec<String> {
+        let file: File = syn::parse2(expansion).unwrap();
+        file.items
+            .iter()
+            .filter_map(|item| match item {
+                Item::Impl(item_impl) => Some(&item_impl.items),
+                _ => None,
+            })
+            .flatten()
+            .filter_map(|impl_item| match impl_item {
+                ImplItem::Fn(method) => Some(method.sig.ident.to_string()),
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_methods_order() {
+        let input: ItemStruct = parse_quote! {
+            struct Foo {
+                #[configure_getter(name = "c_first")]
+                #[configure_getter(name = "c_second")]
+                #[configure_getter_mut]
+                c: u8,
+                a: u8,
+                b: String,
+            }
+        };
+
+        // Getters follow fields and their attributes as declared,
+        // including mutable ones.
+        let method_names =
+            collect_method_names(make_getters(Punctuated::new(), input));
+        assert_eq!(method_names, ["c_first", "c_second", "c_mut", "a", "b"]);
     }
-    .into()
 }
@@ -1,6 +1,8 @@
 mod getter_configs;
 mod getter_methods;
 mod impl_block;
+#[allow(clippy::module_inception)]
 mod implementation;
+mod struct_config;
 
 pub use implementation::do_make_getters;
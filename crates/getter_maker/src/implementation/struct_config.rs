@@ -0,0 +1,109 @@
+use syn::{
+    Error,
+    Expr,
+    ExprLit,
+    Lit,
+    LitBool,
+    LitStr,
+    MetaNameValue,
+    Result,
+    Token,
+    Type,
+    punctuated::Punctuated,
+};
+
+use super::getter_configs::{
+    combine_errors,
+    set_param,
+};
+
+const COPY_TYPES_PARAM: &str = "copy_types";
+const CONST_FN_PARAM: &str = "const_fn";
+
+/// Config of the whole struct, which is made from params passed to
+/// the macro itself.
+#[derive(Debug, Default)]
+pub struct StructConfig {
+    copy_types: Vec<Type>,
+    const_fn: bool,
+}
+
+/// Raw values of macro params. Literals are kept as is, so errors can
+/// point at them.
+#[derive(Debug, Default)]
+struct RawStructParams {
+    copy_types: Option<LitStr>,
+    const_fn: Option<LitBool>,
+}
+
+impl StructConfig {
+    pub fn copy_types(&self) -> &[Type] {
+        &self.copy_types
+    }
+
+    pub fn const_fn(&self) -> bool {
+        self.const_fn
+    }
+}
+
+pub fn make_struct_config(
+    params: Punctuated<MetaNameValue, Token![,]>,
+) -> Result<StructConfig> {
+    let mut raw_params = RawStructParams::default();
+    let mut errors: Vec<Error> = Vec::new();
+
+    for param in params {
+        if let Err(error) = parse_param(param, &mut raw_params) {
+            errors.push(error);
+        }
+    }
+    combine_errors(errors)?;
+
+    // Types are parsed with span of the literal, so errors point at it.
+    let copy_types = match raw_params.copy_types.as_ref() {
+        Some(copy_types) => copy_types
+            .parse_with(Punctuated::<Type, Token![,]>::parse_terminated)?
+            .into_iter()
+            .collect(),
+        None => Vec::new(),
+    };
+
+    Ok(StructConfig {
+        copy_types,
+        const_fn: raw_params.const_fn.as_ref().is_some_and(LitBool::value),
+    })
+}
+
+fn parse_param(
+    param: MetaNameValue,
+    raw_params: &mut RawStructParams,
+) -> Result<()> {
+    let Some(param_ident) = param.path.get_ident() else {
+        return Err(Error::new_spanned(&param.path, "Unexpected param."));
+    };
+    let Expr::Lit(ExprLit { lit, .. }) = param.value else {
+        return Err(Error::new_spanned(
+            &param.value,
+            "Expected literal value.",
+        ));
+    };
+
+    match (param_ident.to_string().as_str(), lit) {
+        (COPY_TYPES_PARAM, Lit::Str(value)) => {
+            set_param(&mut raw_params.copy_types, value, param_ident)
+        }
+        (CONST_FN_PARAM, Lit::Bool(value)) => {
+            set_param(&mut raw_params.const_fn, value, param_ident)
+        }
+        (COPY_TYPES_PARAM, lit) => {
+            Err(Error::new_spanned(lit, "Expected string value."))
+        }
+        (CONST_FN_PARAM, lit) => {
+            Err(Error::new_spanned(lit, "Expected bool value."))
+        }
+        _ => Err(Error::new_spanned(
+            param_ident,
+            format!("Unexpected param '{param_ident}'."),
+        )),
+    }
+}
@@ -1,14 +1,206 @@
 use proc_macro::TokenStream;
 use syn::{
     ItemStruct,
+    MetaNameValue,
+    Token,
     parse_macro_input,
+    punctuated::Punctuated,
 };
 
 mod implementation;
 use implementation::do_make_getters;
 
+/// Generates getter methods for struct fields. Each getter is named
+/// after the field by default, takes `&self` and returns either a
+/// copy of the field value or a reference to it. Mutable getters
+/// returning `&mut T` can be generated on demand.
+///
+/// Apply this macro to a struct using
+/// `#[make_getters]` or `#[make_getters(<params>)]`. The original
+/// struct remains unchanged. The following params are supported:
+/// - `copy_types = "<types>"`: Additional types returned by value,
+///   see below.
+/// - `const_fn = true|false`: Default of `const_fn` option for every
+///   getter of the struct.
+///
+/// By default, getters return primitive scalars, `bool`, `char`,
+/// shared references, pointers, and tuples, arrays and options of
+/// such types by value, and everything else by reference. Since the
+/// macro cannot see trait impls, other `Copy` types returned by value
+/// must be listed in `copy_types` param, e.g.
+/// `#[make_getters(copy_types = "UserId, Point<f32>")]`. Types are
+/// matched exactly as they are written in field declarations.
+///
+/// Multiple `#[configure_getter]` attributes can be applied to a
+/// single field, generating multiple getter methods with the specified
+/// configurations. A bare `#[configure_getter]` generates a getter
+/// with the default configuration.
+///
+/// Mutable getters taking `&mut self` are generated only for fields
+/// with `#[configure_getter_mut]` attributes, one per attribute. They
+/// don't affect generation of the default getter.
+///
+/// Fields of tuple structs get getters only from explicit attributes,
+/// which must set `name`, `prefix` or `suffix`, e.g.
+/// `#[configure_getter(name = "x")]` on field `0`, or
+/// `#[configure_getter(prefix = "get")]` for `get_0`. Unit structs
+/// get no getters.
+///
+/// ### Customization Options
+/// - `#[disable_getters]`: Skip getter generation for a specific
+///   field.
+///
+/// - `#[getter_ref_strategy = "<ref_strategy>"]`: Set the `ref_strategy`
+///   option for every getter of the field that doesn't set it
+///   explicitly.
+///
+/// - `#[configure_getter(
+///       name = "<name>",
+///       visibility = "<vis>",
+///       prefix = "<prefix>",
+///       suffix = "<suffix>",
+///       ref_strategy = "<ref_strategy>",
+///       deref_target = "<type>",
+///       const_fn = true|false,
+///       doc = "<doc>",
+///    )]`:
+///
+///    Configure the getter with the following options:
+///    - `name`: Set a custom method name, overriding prefix/suffix.
+///    - `visibility`: Override method visibility. Set to "" for
+///      `pub(self)`. Default: `pub`.
+///    - `prefix`: Set a prefix. Default: no prefix.
+///    - `suffix`: Override the suffix. Default: field name.
+///    - `ref_strategy`: How the getter returns the field value:
+///      - `"auto"`: returns the value itself or a reference to it,
+///        depending on the field type as described above.
+///      - `"ref"`: returns a reference to the value.
+///      - `"none"`: returns the value itself, so the field type must
+///        be `Copy`.
+///      - `"copy"`: returns a copy of the value. Fails to compile
+///        with a `Copy` bound error if the field type isn't `Copy`.
+///      - `"clone"`: returns a clone of the value. Fails to compile
+///        with a `Clone` bound error if the field type isn't `Clone`.
+///      - `"deref"`: returns a reference to the deref target of the
+///        value, e.g. `&str` for `String`, `&[T]` for `Vec<T>`, `&T`
+///        for `Box<T>`, `Rc<T>` and `Arc<T>`, `&Path` for `PathBuf`.
+///        These types are recognised by bare names and by their
+///        `std::` and `alloc::` paths, e.g. `std::vec::Vec<T>`.
+///      - `"as_deref"`: same as `"deref"`, but for `Option` fields,
+///        e.g. `Option<&str>` for `Option<String>`.
+///
+///      Default: "auto".
+///    - `deref_target`: Set the deref target type explicitly, e.g.
+///      for custom `Deref` types. Can be used only with `"deref"`
+///      and `"as_deref"` ref strategies.
+///    - `const_fn`: Whether to generate `const fn`. Cannot be used
+///      with `"clone"`, `"deref"` and `"as_deref"` ref strategies.
+///      Default: false.
+///    - `doc`: Override docs of the method. Default: generated lead
+///      line followed by docs of the field.
+///
+/// - `#[configure_getter_mut(
+///       name = "<name>",
+///       visibility = "<vis>",
+///       prefix = "<prefix>",
+///       suffix = "<suffix>",
+///       ref_strategy = "ref"|"as_mut",
+///       const_fn = true|false,
+///       doc = "<doc>",
+///    )]`:
+///
+///    Configure the mutable getter with the following options:
+///    - `name`, `visibility`, `prefix`, `const_fn`, `doc`: Same as
+///      for `configure_getter`.
+///    - `suffix`: Override the suffix. Default: `<field_name>_mut`.
+///    - `ref_strategy`: Whether the getter returns `&mut T` (`"ref"`)
+///      or, for `Option<T>` fields, `Option<&mut T>` (`"as_mut"`).
+///      Default: "ref".
+///
+/// # Example
+/// ```rust
+/// use useless_getter_maker::make_getters;
+///
+/// #[make_getters]
+/// #[derive(Debug, PartialEq, Default)]
+/// struct Foo {
+///     bar: u16,
+///
+///     #[getter_ref_strategy = "ref"]
+///     baz: String,
+///
+///     #[configure_getter(prefix = "get", visibility = "pub(crate)")]
+///     foobar: bool,
+///
+///     #[configure_getter(name = "provide_bb", ref_strategy = "ref")]
+///     barbaz: Vec<u8>,
+///
+///     #[getter_ref_strategy = "ref"]
+///     #[configure_getter]
+///     #[configure_getter(suffix = "bf_value", ref_strategy = "none")]
+///     bazfoo: u32,
+///
+///     #[getter_ref_strategy = "deref"]
+///     bazbar: String,
+///
+///     #[getter_ref_strategy = "as_deref"]
+///     bazbaz: Option<Vec<u8>>,
+///
+///     #[configure_getter_mut]
+///     #[configure_getter_mut(suffix = "bq_mut", ref_strategy = "as_mut")]
+///     bazqux: Option<u8>,
+///
+///     #[disable_getters]
+///     foobaz: bool,
+/// }
+///
+/// let mut foo = Foo {
+///     bar: 100,
+///     baz: String::from("some_text"),
+///     foobar: true,
+///     barbaz: vec![1, 2, 3],
+///     bazfoo: 7,
+///     bazbar: String::from("other_text"),
+///     bazbaz: Some(vec![4, 5]),
+///     bazqux: None,
+///     foobaz: false,
+/// };
+///
+/// assert_eq!(foo.bar(), 100);                         // Pub
+/// assert_eq!(foo.baz(), &String::from("some_text"));  // Pub
+/// assert_eq!(foo.get_foobar(), true);                 // Pub(crate)
+/// assert_eq!(foo.provide_bb(), &vec![1, 2, 3]);       // Pub
+/// assert_eq!(foo.bazfoo(), &7);                       // Pub, first getter
+/// assert_eq!(foo.bf_value(), 7);                      // Pub, second getter
+/// assert_eq!(foo.bazbar(), "other_text");             // Pub
+/// assert_eq!(foo.bazbaz(), Some(&[4, 5][..]));        // Pub
+///
+/// *foo.bazqux_mut() = Some(1);                        // Pub, mutable getter
+/// *foo.bq_mut().unwrap() += 1;                        // Pub, mutable getter
+/// assert_eq!(foo.bazqux(), Some(2));                  // Pub
+/// ```
+///
+/// # Errors
+/// Invalid params are reported as compile errors pointing at the
+/// offending param or value. Errors of all fields are reported
+/// together, and the struct itself is still emitted.
+/// ```compile_fail
+/// use useless_getter_maker::make_getters;
+///
+/// #[make_getters]
+/// struct Foo {
+///     #[configure_getter(ref_strategy = "borrow")] // Invalid strategy
+///     bar: u16,
+///
+///     #[getter_ref_strategy = "as_deref"]          // Not an Option
+///     baz: String,
+/// }
+/// ```
 #[proc_macro_attribute]
-pub fn make_getters(_: TokenStream, input: TokenStream) -> TokenStream {
+pub fn make_getters(params: TokenStream, input: TokenStream) -> TokenStream {
+    let params = parse_macro_input!(
+        params with Punctuated::<MetaNameValue, Token![,]>::parse_terminated
+    );
     let input = parse_macro_input!(input as ItemStruct);
-    do_make_getters(input)
+    do_make_getters(params, input)
 }
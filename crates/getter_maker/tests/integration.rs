@@ -1,7 +1,4 @@
-use std::{
-    ascii,
-    fmt::Debug,
-};
+use std::fmt::Debug;
 
 use useless_getter_maker::make_getters;
 
@@ -45,3 +42,399 @@ fn test_basic_scenario_with_generics() {
     assert_eq!(foo.bar(), 12);
     assert_eq!(foo.baz(), &vec![1, 2, 3]);
 }
+
+#[test]
+fn test_configure_getter() {
+    #[make_getters]
+    #[derive(Debug, PartialEq, Default)]
+    struct Foo {
+        #[configure_getter(prefix = "get")]
+        bar: u16,
+        #[configure_getter(name = "provide_baz", ref_strategy = "ref")]
+        baz: String,
+        #[configure_getter(prefix = "get", suffix = "fb", visibility = "")]
+        foobar: bool,
+        #[configure_getter(suffix = "bb", visibility = "pub(crate)")]
+        barbaz: char,
+        #[disable_getters]
+        #[allow(dead_code)]
+        foobaz: Vec<u8>,
+    }
+
+    let foo = Foo {
+        bar: 12,
+        baz: String::from("asd"),
+        foobar: true,
+        barbaz: 'x',
+        foobaz: vec![1],
+    };
+
+    assert_eq!(foo.get_bar(), 12);
+    assert_eq!(foo.provide_baz(), &String::from("asd"));
+    assert!(foo.get_fb());
+    assert_eq!(foo.bb(), 'x');
+}
+
+#[test]
+fn test_with_setters() {
+    use useless_setter_maker::make_basic_setters;
+
+    #[make_getters]
+    #[make_basic_setters]
+    #[derive(Debug, PartialEq, Default)]
+    struct Foo {
+        #[basic_setter(with_into = false)]
+        bar: u16,
+        #[getter_ref_strategy = "ref"]
+        #[basic_setter(prefix = "install")]
+        baz: String,
+    }
+
+    let mut foo = Foo::default();
+    foo.set_bar(12);
+    foo.install_baz("asd");
+
+    assert_eq!(foo.bar(), 12);
+    assert_eq!(foo.baz(), &String::from("asd"));
+}
+
+#[test]
+fn test_multiple_getters_per_field() {
+    #[make_getters]
+    #[derive(Debug, PartialEq, Default)]
+    struct Foo {
+        #[configure_getter]
+        #[configure_getter(prefix = "get")]
+        #[configure_getter(suffix = "bar_ref", ref_strategy = "ref")]
+        bar: u16,
+        #[getter_ref_strategy = "ref"]
+        #[configure_getter]
+        #[configure_getter(name = "baz_value", ref_strategy = "none")]
+        baz: bool,
+    }
+
+    let foo = Foo { bar: 12, baz: true };
+
+    assert_eq!(foo.bar(), 12);
+    assert_eq!(foo.get_bar(), 12);
+    assert_eq!(foo.bar_ref(), &12);
+    assert_eq!(foo.baz(), &true);
+    assert!(foo.baz_value());
+}
+
+#[test]
+fn test_copy_and_clone_ref_strategies() {
+    use std::sync::Arc;
+
+    #[make_getters]
+    #[derive(Debug, PartialEq, Default)]
+    struct Foo {
+        #[configure_getter(ref_strategy = "copy")]
+        bar: u16,
+        #[configure_getter(ref_strategy = "clone")]
+        baz: String,
+        #[getter_ref_strategy = "ref"]
+        #[configure_getter]
+        #[configure_getter(suffix = "foobar_owned", ref_strategy = "clone")]
+        foobar: Vec<u8>,
+        #[getter_ref_strategy = "clone"]
+        barbaz: Arc<str>,
+    }
+
+    let foo = Foo {
+        bar: 12,
+        baz: String::from("asd"),
+        foobar: vec![1, 2, 3],
+        barbaz: Arc::from("qwe"),
+    };
+
+    assert_eq!(foo.bar(), 12);
+    assert_eq!(foo.baz(), String::from("asd"));
+    assert_eq!(foo.foobar(), &vec![1, 2, 3]);
+    assert_eq!(foo.foobar_owned(), vec![1, 2, 3]);
+    assert_eq!(Arc::strong_count(&foo.barbaz()), 2);
+}
+
+#[test]
+fn test_deref_ref_strategies() {
+    use std::{
+        path::{
+            Path,
+            PathBuf,
+        },
+        rc::Rc,
+    };
+
+    struct Wrapper(String);
+
+    impl std::ops::Deref for Wrapper {
+        type Target = String;
+
+        fn deref(&self) -> &Self::Target {
+            &self.0
+        }
+    }
+
+    #[make_getters]
+    struct Foo {
+        #[getter_ref_strategy = "deref"]
+        bar: String,
+        #[getter_ref_strategy = "deref"]
+        baz: Vec<u8>,
+        #[getter_ref_strategy = "deref"]
+        foobar: Box<u16>,
+        #[getter_ref_strategy = "deref"]
+        barbaz: PathBuf,
+        #[getter_ref_strategy = "deref"]
+        foobaz: Rc<[u8]>,
+        #[configure_getter(ref_strategy = "deref", deref_target = "str")]
+        bazfoo: Wrapper,
+        #[getter_ref_strategy = "as_deref"]
+        bazbar: Option<String>,
+        #[configure_getter(ref_strategy = "as_deref", deref_target = "[u8]")]
+        bazbaz: Option<Vec<u8>>,
+    }
+
+    let foo = Foo {
+        bar: String::from("asd"),
+        baz: vec![1, 2, 3],
+        foobar: Box::new(12),
+        barbaz: PathBuf::from("/tmp"),
+        foobaz: Rc::from(vec![4, 5]),
+        bazfoo: Wrapper(String::from("qwe")),
+        bazbar: Some(String::from("zxc")),
+        bazbaz: None,
+    };
+
+    let bar: &str = foo.bar();
+    let baz: &[u8] = foo.baz();
+
+    assert_eq!(bar, "asd");
+    assert_eq!(baz, &[1, 2, 3]);
+    assert_eq!(foo.foobar(), &12);
+    assert_eq!(foo.barbaz(), Path::new("/tmp"));
+    assert_eq!(foo.foobaz(), &[4, 5]);
+    assert_eq!(foo.bazfoo(), "qwe");
+    assert_eq!(foo.bazbar(), Some("zxc"));
+    assert_eq!(foo.bazbaz(), None);
+}
+
+#[test]
+fn test_deref_ref_strategy_of_qualified_paths() {
+    #[make_getters]
+    struct Foo {
+        #[getter_ref_strategy = "deref"]
+        bar: std::string::String,
+        #[getter_ref_strategy = "deref"]
+        baz: ::std::vec::Vec<u8>,
+        #[getter_ref_strategy = "deref"]
+        foobar: std::sync::Arc<u16>,
+        #[getter_ref_strategy = "as_deref"]
+        barbaz: Option<std::boxed::Box<str>>,
+    }
+
+    let foo = Foo {
+        bar: String::from("asd"),
+        baz: vec![1, 2],
+        foobar: std::sync::Arc::new(3),
+        barbaz: Some(Box::from("qwe")),
+    };
+
+    let bar: &str = foo.bar();
+    let baz: &[u8] = foo.baz();
+
+    assert_eq!(bar, "asd");
+    assert_eq!(baz, &[1, 2]);
+    assert_eq!(foo.foobar(), &3);
+    assert_eq!(foo.barbaz(), Some("qwe"));
+}
+
+#[test]
+fn test_mutable_getters() {
+    #[make_getters]
+    #[derive(Debug, PartialEq, Default)]
+    struct Foo {
+        #[configure_getter_mut]
+        bar: u16,
+        #[getter_ref_strategy = "ref"]
+        #[configure_getter_mut(prefix = "get")]
+        baz: String,
+        #[configure_getter(ref_strategy = "as_deref")]
+        #[configure_getter_mut(ref_strategy = "as_mut")]
+        #[configure_getter_mut(name = "foobar_option_mut")]
+        foobar: Option<String>,
+    }
+
+    let mut foo = Foo::default();
+
+    *foo.bar_mut() = 12;
+    foo.get_baz_mut().push_str("asd");
+    assert_eq!(foo.foobar_mut(), None);
+    *foo.foobar_option_mut() = Some(String::from("qwe"));
+    foo.foobar_mut().unwrap().push('r');
+
+    assert_eq!(foo.bar(), 12);
+    assert_eq!(foo.baz(), "asd");
+    assert_eq!(foo.foobar(), Some("qwer"));
+}
+
+#[test]
+fn test_auto_ref_strategy() {
+    #[derive(Debug, PartialEq, Clone, Copy)]
+    struct Id(u32);
+
+    #[derive(Debug, PartialEq, Clone, Copy)]
+    struct Point<T>(T, T);
+
+    #[make_getters(copy_types = "Id, Point<f32>")]
+    #[derive(Debug, PartialEq)]
+    struct Foo<'a> {
+        bar: u16,
+        baz: String,
+        foobar: &'a str,
+        barbaz: (bool, char),
+        foobaz: [f64; 2],
+        bazfoo: Option<i8>,
+        bazbar: Option<String>,
+        bazbaz: Id,
+        quxfoo: Point<f32>,
+        quxbar: Point<f64>,
+        #[getter_ref_strategy = "auto"]
+        quxbaz: Vec<u8>,
+    }
+
+    let foo = Foo {
+        bar: 12,
+        baz: String::from("asd"),
+        foobar: "qwe",
+        barbaz: (true, 'x'),
+        foobaz: [1.0, 2.0],
+        bazfoo: Some(1),
+        bazbar: None,
+        bazbaz: Id(3),
+        quxfoo: Point(1.0, 2.0),
+        quxbar: Point(3.0, 4.0),
+        quxbaz: vec![1],
+    };
+
+    assert_eq!(foo.bar(), 12);
+    assert_eq!(foo.baz(), &String::from("asd"));
+    assert_eq!(foo.foobar(), "qwe");
+    assert_eq!(foo.barbaz(), (true, 'x'));
+    assert_eq!(foo.foobaz(), [1.0, 2.0]);
+    assert_eq!(foo.bazfoo(), Some(1));
+    assert_eq!(foo.bazbar(), &None);
+    assert_eq!(foo.bazbaz(), Id(3));
+    assert_eq!(foo.quxfoo(), Point(1.0, 2.0));
+    assert_eq!(foo.quxbar(), &Point(3.0, 4.0));
+    assert_eq!(foo.quxbaz(), &vec![1]);
+}
+
+#[test]
+fn test_const_fn_getters() {
+    #[make_getters(const_fn = true)]
+    #[derive(Debug, PartialEq)]
+    struct Foo {
+        bar: u16,
+        #[configure_getter(ref_strategy = "ref")]
+        baz: [u8; 2],
+        #[configure_getter]
+        #[configure_getter(
+            suffix = "foobar_str",
+            ref_strategy = "deref",
+            deref_target = "str",
+            const_fn = false
+        )]
+        foobar: &'static str,
+        #[configure_getter_mut(ref_strategy = "as_mut")]
+        barbaz: Option<u8>,
+    }
+
+    const FOO: Foo = Foo {
+        bar: 12,
+        baz: [1, 2],
+        foobar: "asd",
+        barbaz: None,
+    };
+    const BAR: u16 = FOO.bar();
+    const BAZ: &[u8; 2] = FOO.baz();
+    const FOOBAR: &str = FOO.foobar();
+    const BARBAZ: Option<u8> = {
+        let mut foo = FOO;
+        if let Some(barbaz) = foo.barbaz_mut() {
+            *barbaz += 1;
+        }
+        foo.barbaz
+    };
+
+    assert_eq!(BAR, 12);
+    assert_eq!(BAZ, &[1, 2]);
+    assert_eq!(FOOBAR, "asd");
+    assert_eq!(BARBAZ, None);
+    assert_eq!(FOO.foobar_str(), "asd");
+}
+
+#[test]
+fn test_raw_identifier_fields() {
+    use useless_setter_maker::make_basic_setters;
+
+    #[make_getters]
+    #[make_basic_setters]
+    #[derive(Debug, Default)]
+    struct Foo {
+        r#type: u8,
+        #[configure_getter(prefix = "get")]
+        #[configure_getter_mut]
+        r#move: String,
+    }
+
+    let mut foo = Foo::default();
+    foo.set_type(3);
+    foo.set_move("left");
+    foo.move_mut().push('!');
+
+    assert_eq!(foo.r#type(), 3);
+    assert_eq!(foo.get_move(), "left!");
+}
+
+#[test]
+fn test_tuple_structs() {
+    #[make_getters]
+    #[derive(Debug, Default)]
+    struct Point(
+        #[configure_getter(name = "x")] f32,
+        #[configure_getter(prefix = "get")]
+        #[configure_getter_mut(suffix = "y_mut")]
+        f32,
+        String,
+    );
+
+    #[make_getters]
+    struct Marker;
+
+    let mut point = Point(1.5, 2.0, String::from("origin"));
+    *point.y_mut() += 1.0;
+
+    assert_eq!(point.x(), 1.5);
+    assert_eq!(point.get_1(), 3.0);
+    assert_eq!(point.2, "origin");
+    let _ = Marker;
+}
+
+/// Fails to compile if any generated getter is left undocumented.
+#[deny(missing_docs)]
+pub mod documented_getters {
+    use useless_getter_maker::make_getters;
+
+    /// Struct with documented getters.
+    #[make_getters]
+    pub struct Config {
+        /// Port to listen on.
+        #[configure_getter_mut]
+        port: u16,
+        #[configure_getter(doc = "Returns host name.")]
+        host: String,
+        #[getter_ref_strategy = "clone"]
+        fooport: Vec<u8>,
+    }
+}
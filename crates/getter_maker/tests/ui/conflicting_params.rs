@@ -0,0 +1,9 @@
+use useless_getter_maker::make_getters;
+
+#[make_getters]
+struct Foo {
+    #[configure_getter(name = "a", suffix = "b")]
+    bar: u16,
+}
+
+fn main() {}
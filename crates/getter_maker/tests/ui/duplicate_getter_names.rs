@@ -0,0 +1,13 @@
+use useless_getter_maker::make_getters;
+
+#[make_getters]
+struct Foo {
+    #[configure_getter_mut]
+    bar: u8,
+    bar_mut: u8,
+    #[configure_getter(name = "qux")]
+    #[configure_getter(name = "qux")]
+    baz: u8,
+}
+
+fn main() {}
@@ -0,0 +1,13 @@
+use useless_getter_maker::make_getters;
+
+mod custom {
+    pub struct Vec<T>(pub T);
+}
+
+#[make_getters]
+struct Foo {
+    #[getter_ref_strategy = "deref"]
+    bar: custom::Vec<u8>,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use useless_getter_maker::make_getters;
+
+#[make_getters(copy_typess = "Foo", const_fn = true, const_fn = false)]
+struct Foo {
+    #[configure_getter(sufix = "a")]
+    bar: u16,
+}
+
+fn main() {}
@@ -0,0 +1,492 @@
+use proc_macro2::{
+    Span as Span2,
+    TokenStream as TokenStream2,
+};
+use quote::{
+    format_ident,
+    quote,
+};
+use syn::{
+    Field,
+    Member,
+    Type,
+};
+
+use super::{
+    docs::make_docs,
+    setter_configs::{
+        KeyedCollection,
+        SetterConfig,
+        make_field_name,
+    },
+};
+
+const CLEAR_PREFIX: &str = "clear";
+const TAKE_PREFIX: &str = "take";
+const GET_OR_INSERT_WITH_SUFFIX: &str = "get_or_insert_with";
+const EXTEND_PREFIX: &str = "extend";
+const REPLACE_PREFIX: &str = "replace";
+const SWAP_PREFIX: &str = "swap";
+const UPDATE_PREFIX: &str = "update";
+const INSERT_PREFIX: &str = "insert";
+const ADD_PREFIX: &str = "add";
+const REMOVE_PREFIX: &str = "remove";
+const CONTAINS_PREFIX: &str = "contains";
+
+/// Makes helper methods of setter, which are enabled by its config.
+/// They share visibility of the setter and are named after its
+/// suffix.
+pub fn make_helper_methods(
+    member: &Member,
+    field: &Field,
+    field_setter_config: &SetterConfig,
+) -> Vec<TokenStream2> {
+    let mut helper_methods: Vec<TokenStream2> = Vec::new();
+
+    if field_setter_config.option_helpers() {
+        helper_methods.extend(make_option_helper_methods(
+            member,
+            field,
+            field_setter_config,
+        ));
+    }
+    if field_setter_config.update() {
+        helper_methods.push(make_update_method(
+            member,
+            field,
+            field_setter_config,
+        ));
+    }
+    helper_methods.extend(make_mem_helper_methods(
+        member,
+        field,
+        field_setter_config,
+    ));
+    if let Some(each) = field_setter_config.each() {
+        helper_methods.extend(make_sequence_helper_methods(
+            member,
+            field,
+            field_setter_config,
+            each,
+        ));
+    }
+    if let Some(singular) = field_setter_config.singular() {
+        helper_methods.extend(make_keyed_collection_helper_methods(
+            member,
+            field,
+            field_setter_config,
+            singular,
+        ));
+    }
+
+    helper_methods
+}
+
+/// Makes `clear_<suffix>`, `take_<suffix>` and
+/// `<suffix>_get_or_insert_with` methods of `Option` field.
+fn make_option_helper_methods(
+    member: &Member,
+    field: &Field,
+    field_setter_config: &SetterConfig,
+) -> Vec<TokenStream2> {
+    let Some(inner_type) = field_setter_config.option_inner_type() else {
+        return Vec::new();
+    };
+    let field_type = &field.ty;
+    let field_name = make_field_name(member);
+    let suffix = field_setter_config.suffix();
+    let method_visibility = field_setter_config.visibility();
+    let span = Span2::call_site();
+
+    let clear_name = format_ident!("{CLEAR_PREFIX}_{suffix}", span = span);
+    let clear_docs =
+        make_docs(format!("Sets `{field_name}` to `None`."), field, None);
+    let take_name = format_ident!("{TAKE_PREFIX}_{suffix}", span = span);
+    let take_docs = make_docs(
+        format!(
+            "Takes the value of `{field_name}` out, leaving `None` in \
+            its place."
+        ),
+        field,
+        None,
+    );
+    let get_or_insert_with_name =
+        format_ident!("{suffix}_{GET_OR_INSERT_WITH_SUFFIX}", span = span);
+    let get_or_insert_with_docs = make_docs(
+        format!(
+            "Returns a mutable reference to the value of `{field_name}`, \
+            inserting the value computed by `f` if it is `None`."
+        ),
+        field,
+        None,
+    );
+
+    vec![
+        quote! {
+            #clear_docs
+            #method_visibility fn #clear_name(&mut self) {
+                self.#member = None;
+            }
+        },
+        quote! {
+            #take_docs
+            #method_visibility fn #take_name(&mut self) -> #field_type {
+                self.#member.take()
+            }
+        },
+        quote! {
+            #get_or_insert_with_docs
+            #method_visibility fn #get_or_insert_with_name(
+                &mut self,
+                f: impl FnOnce() -> #inner_type,
+            ) -> &mut #inner_type {
+                self.#member.get_or_insert_with(f)
+            }
+        },
+    ]
+}
+
+/// Makes `update_<suffix>` method, which changes the value of field by
+/// a closure. Closure gets inner value of stripped `Option` field, so
+/// it's called only when the field is `Some`.
+fn make_update_method(
+    member: &Member,
+    field: &Field,
+    field_setter_config: &SetterConfig,
+) -> TokenStream2 {
+    let value_type = field_setter_config.value_type(&field.ty);
+    let field_name = make_field_name(member);
+    let suffix = field_setter_config.suffix();
+    let method_visibility = field_setter_config.visibility();
+    let span = Span2::call_site();
+
+    let update_name = format_ident!("{UPDATE_PREFIX}_{suffix}", span = span);
+    let update = if field_setter_config.strip_option()
+        && field_setter_config.option_inner_type().is_some()
+    {
+        quote! {
+            if let Some(value) = &mut self.#member {
+                f(value);
+            }
+        }
+    } else {
+        quote! { f(&mut self.#member); }
+    };
+    let update_docs = make_docs(
+        format!("Changes the value of `{field_name}` by `f`."),
+        field,
+        None,
+    );
+
+    quote! {
+        #update_docs
+        #method_visibility fn #update_name(
+            &mut self,
+            f: impl FnOnce(&mut #value_type),
+        ) {
+            #update
+        }
+    }
+}
+
+/// Makes `replace_<suffix>`, `take_<suffix>` and `swap_<suffix>`
+/// methods, which are enabled by the config. Values are replaced like
+/// the setter sets them, so they respect `with_into` and stripped
+/// options.
+fn make_mem_helper_methods(
+    member: &Member,
+    field: &Field,
+    field_setter_config: &SetterConfig,
+) -> Vec<TokenStream2> {
+    let field_type = &field.ty;
+    let value_type = field_setter_config.value_type(field_type);
+    let field_name = make_field_name(member);
+    let suffix = field_setter_config.suffix();
+    let method_visibility = field_setter_config.visibility();
+    let span = Span2::call_site();
+    let mut mem_helper_methods: Vec<TokenStream2> = Vec::new();
+
+    if field_setter_config.replace() {
+        let (param_type, value) = if field_setter_config.with_into() {
+            (quote! { impl Into<#value_type> }, quote! { value.into() })
+        } else {
+            (quote! { #value_type }, quote! { value })
+        };
+        let replace = if field_setter_config.strip_option()
+            && field_setter_config.option_inner_type().is_some()
+        {
+            quote! { self.#member.replace(#value) }
+        } else {
+            quote! { ::core::mem::replace(&mut self.#member, #value) }
+        };
+        let replace_name =
+            format_ident!("{REPLACE_PREFIX}_{suffix}", span = span);
+        let replace_docs = make_docs(
+            format!(
+                "Sets the value of `{field_name}` and returns the \
+                previous one."
+            ),
+            field,
+            None,
+        );
+
+        mem_helper_methods.push(quote! {
+            #replace_docs
+            #method_visibility fn #replace_name(
+                &mut self,
+                value: #param_type,
+            ) -> #field_type {
+                #replace
+            }
+        });
+    }
+    if field_setter_config.take() {
+        let take_name = format_ident!("{TAKE_PREFIX}_{suffix}", span = span);
+        let take_docs = make_docs(
+            format!(
+                "Takes the value of `{field_name}` out, leaving the \
+                default value in its place."
+            ),
+            field,
+            None,
+        );
+
+        mem_helper_methods.push(quote! {
+            #take_docs
+            #method_visibility fn #take_name(&mut self) -> #field_type {
+                ::core::mem::take(&mut self.#member)
+            }
+        });
+    }
+    if field_setter_config.swap() {
+        let swap_name = format_ident!("{SWAP_PREFIX}_{suffix}", span = span);
+        let swap_docs = make_docs(
+            format!("Swaps the value of `{field_name}` with `other`."),
+            field,
+            None,
+        );
+
+        mem_helper_methods.push(quote! {
+            #swap_docs
+            #method_visibility fn #swap_name(
+                &mut self,
+                other: &mut #field_type,
+            ) {
+                ::core::mem::swap(&mut self.#member, other);
+            }
+        });
+    }
+
+    mem_helper_methods
+}
+
+/// Makes `<each>` and `extend_<suffix>` methods of sequence field,
+/// which push a single item and items of iterator into it.
+fn make_sequence_helper_methods(
+    member: &Member,
+    field: &Field,
+    field_setter_config: &SetterConfig,
+    each: &str,
+) -> Vec<TokenStream2> {
+    let Some(item_type) = field_setter_config.sequence_item_type() else {
+        return Vec::new();
+    };
+    let field_name = make_field_name(member);
+    let suffix = field_setter_config.suffix();
+    let method_visibility = field_setter_config.visibility();
+    let span = Span2::call_site();
+
+    let (item_param_type, item, items) = if field_setter_config.with_into() {
+        (
+            quote! { impl Into<#item_type> },
+            quote! { item.into() },
+            quote! { iter.into_iter().map(Into::into) },
+        )
+    } else {
+        (quote! { #item_type }, quote! { item }, quote! { iter })
+    };
+
+    let push_name = format_ident!("{}", each, span = span);
+    let push_docs =
+        make_docs(format!("Pushes an item into `{field_name}`."), field, None);
+    let extend_name = format_ident!("{EXTEND_PREFIX}_{suffix}", span = span);
+    let extend_docs = make_docs(
+        format!("Extends `{field_name}` with items of `iter`."),
+        field,
+        None,
+    );
+
+    vec![
+        quote! {
+            #push_docs
+            #method_visibility fn #push_name(
+                &mut self,
+                item: #item_param_type,
+            ) {
+                self.#member.extend(::core::iter::once(#item));
+            }
+        },
+        quote! {
+            #extend_docs
+            #method_visibility fn #extend_name(
+                &mut self,
+                iter: impl IntoIterator<Item = #item_param_type>,
+            ) {
+                self.#member.extend(#items);
+            }
+        },
+    ]
+}
+
+/// Makes `insert_<singular>`, `remove_<singular>` and
+/// `contains_<singular>` methods of map field, or `add_<singular>`,
+/// `remove_<singular>` and `contains_<singular>` methods of set field.
+fn make_keyed_collection_helper_methods(
+    member: &Member,
+    field: &Field,
+    field_setter_config: &SetterConfig,
+    singular: &str,
+) -> Vec<TokenStream2> {
+    let Some(keyed_collection) = field_setter_config.keyed_collection() else {
+        return Vec::new();
+    };
+    let field_name = make_field_name(member);
+    let method_visibility = field_setter_config.visibility();
+    let with_into = field_setter_config.with_into();
+    let span = Span2::call_site();
+    let param = |name: &str, param_type: &Type| {
+        let name = format_ident!("{name}");
+        if with_into {
+            (
+                quote! { #name: impl Into<#param_type> },
+                quote! { #name.into() },
+            )
+        } else {
+            (quote! { #name: #param_type }, quote! { #name })
+        }
+    };
+
+    let remove_name = format_ident!("{REMOVE_PREFIX}_{singular}", span = span);
+    let contains_name =
+        format_ident!("{CONTAINS_PREFIX}_{singular}", span = span);
+
+    match keyed_collection {
+        KeyedCollection::Map {
+            key_type,
+            value_type,
+        } => {
+            let (key_param, key) = param("key", key_type);
+            let (value_param, value) = param("value", value_type);
+            let insert_name =
+                format_ident!("{INSERT_PREFIX}_{singular}", span = span);
+            let insert_docs = make_docs(
+                format!(
+                    "Inserts an entry into `{field_name}` and returns the \
+                    previous value of the key."
+                ),
+                field,
+                None,
+            );
+            let remove_docs = make_docs(
+                format!(
+                    "Removes an entry from `{field_name}` and returns its \
+                    value."
+                ),
+                field,
+                None,
+            );
+            let contains_docs = make_docs(
+                format!("Returns whether `{field_name}` contains the key."),
+                field,
+                None,
+            );
+
+            vec![
+                quote! {
+                    #insert_docs
+                    #method_visibility fn #insert_name(
+                        &mut self,
+                        #key_param,
+                        #value_param,
+                    ) -> Option<#value_type> {
+                        self.#member.insert(#key, #value)
+                    }
+                },
+                quote! {
+                    #remove_docs
+                    #method_visibility fn #remove_name(
+                        &mut self,
+                        key: &#key_type,
+                    ) -> Option<#value_type> {
+                        self.#member.remove(key)
+                    }
+                },
+                quote! {
+                    #contains_docs
+                    #method_visibility fn #contains_name(
+                        &self,
+                        key: &#key_type,
+                    ) -> bool {
+                        self.#member.contains_key(key)
+                    }
+                },
+            ]
+        }
+        KeyedCollection::Set { item_type } => {
+            let (item_param, item) = param("item", item_type);
+            let add_name =
+                format_ident!("{ADD_PREFIX}_{singular}", span = span);
+            let add_docs = make_docs(
+                format!(
+                    "Adds an item into `{field_name}` and returns whether \
+                    it was not present."
+                ),
+                field,
+                None,
+            );
+            let remove_docs = make_docs(
+                format!(
+                    "Removes an item from `{field_name}` and returns \
+                    whether it was present."
+                ),
+                field,
+                None,
+            );
+            let contains_docs = make_docs(
+                format!("Returns whether `{field_name}` contains the item."),
+                field,
+                None,
+            );
+
+            vec![
+                quote! {
+                    #add_docs
+                    #method_visibility fn #add_name(
+                        &mut self,
+                        #item_param,
+                    ) -> bool {
+                        self.#member.insert(#item)
+                    }
+                },
+                quote! {
+                    #remove_docs
+                    #method_visibility fn #remove_name(
+                        &mut self,
+                        item: &#item_type,
+                    ) -> bool {
+                        self.#member.remove(item)
+                    }
+                },
+                quote! {
+                    #contains_docs
+                    #method_visibility fn #contains_name(
+                        &self,
+                        item: &#item_type,
+                    ) -> bool {
+                        self.#member.contains(item)
+                    }
+                },
+            ]
+        }
+    }
+}
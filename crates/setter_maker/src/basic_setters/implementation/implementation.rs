@@ -1,22 +1,142 @@
 use proc_macro::TokenStream as TokenStream1;
+use proc_macro2::TokenStream as TokenStream2;
 use quote::quote;
-use syn::ItemStruct;
+use syn::{
+    Error,
+    ItemStruct,
+    MetaNameValue,
+    Token,
+    punctuated::Punctuated,
+};
 
 use super::{
+    error_enum::make_error_enum,
     impl_block::make_impl_block,
     setter_configs::make_setter_configs,
+    setter_kind::SetterKind,
     setter_methods::make_setter_methods,
+    struct_config::{
+        StructConfig,
+        make_struct_config,
+    },
 };
 
-pub fn do_make_basic_setters(mut item: ItemStruct) -> TokenStream1 {
-    let setter_configs = make_setter_configs(&mut item.fields);
-    let setter_methods = make_setter_methods(setter_configs);
-    let impl_block =
-        make_impl_block(&item.ident, &item.generics, setter_methods);
+pub fn do_make_basic_setters(
+    params: Punctuated<MetaNameValue, Token![,]>,
+    item: ItemStruct,
+) -> TokenStream1 {
+    make_basic_setters(params, item).into()
+}
+
+fn make_basic_setters(
+    params: Punctuated<MetaNameValue, Token![,]>,
+    mut item: ItemStruct,
+) -> TokenStream2 {
+    let mut errors: Vec<Error> = Vec::new();
+
+    // Config attributes must be removed from fields even if params
+    // of the macro are invalid, so the default config is used then.
+    let struct_config = make_struct_config(params, SetterKind::Basic)
+        .unwrap_or_else(|error| {
+            errors.push(error);
+            StructConfig::new(SetterKind::Basic)
+        });
+    let impl_block = match make_setter_configs(
+        &mut item.fields,
+        &item.generics,
+        &struct_config,
+    ) {
+        Ok(setter_configs) => {
+            let error_enum = make_error_enum(
+                &item.ident,
+                &item.vis,
+                &setter_configs,
+                SetterKind::Basic,
+            );
+            let setter_methods =
+                make_setter_methods(&item.ident, setter_configs);
+            let impl_block =
+                make_impl_block(&item.ident, &item.generics, setter_methods);
+            quote! {
+                #error_enum
+                #impl_block
+            }
+        }
+        Err(error) => {
+            errors.push(error);
+            TokenStream2::new()
+        }
+    };
+    let errors = errors.iter().map(Error::to_compile_error);
 
     quote! {
         #item
         #impl_block
+        #(#errors)*
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proc_macro2::TokenStream as TokenStream2;
+    use syn::{
+        File,
+        ImplItem,
+        Item,
+        ItemStruct,
+        parse_quote,
+        punctuated::Punctuated,
+    };
+
+    use super::make_basic_setters;
+
+    /// Returns names of methods of impl blocks in order of expansion.
+    fn collect_method_names(expansion: TokenStream2) -> Vec<String> {
+        let file: File = syn::parse2(expansion).unwrap();
+        file.items
+            .iter()
+            .filter_map(|item| match item {
+                Item::Impl(item_impl) => Some(&item_impl.items),
+                _ => None,
+            })
+            .flatten()
+            .filter_map(|impl_item| match impl_item {
+                ImplItem::Fn(method) => Some(method.sig.ident.to_string()),
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_methods_order() {
+        let item: ItemStruct = parse_quote! {
+            struct Foo {
+                #[basic_setter(name = "set_c_first")]
+                #[basic_setter(name = "set_c_second", option_helpers = true)]
+                c: Option<u8>,
+                a: u8,
+                #[basic_setter(each = "push_b")]
+                b: Vec<u8>,
+            }
+        };
+
+        // Fields are declared out of alphabetical order, so only
+        // declaration order of fields and attributes passes.
+        let method_names =
+            collect_method_names(make_basic_setters(Punctuated::new(), item));
+        assert_eq!(
+            method_names,
+            [
+                "set_c_first",
+                "set_c_second",
+                "clear_c",
+                "take_c",
+                "c_get_or_insert_with",
+                "set_a",
+                "set_b",
+                "push_b",
+                "extend_b",
+            ]
+        );
     }
-    .into()
 }
@@ -1,6 +1,15 @@
+mod helper_methods;
 mod impl_block;
+#[allow(clippy::module_inception)]
 mod implementation;
-mod setter_configs;
 mod setter_methods;
 
+use crate::config::{
+    docs,
+    error_enum,
+    setter_configs,
+    setter_kind,
+    struct_config,
+};
+
 pub use implementation::do_make_basic_setters;
@@ -2,101 +2,231 @@ use proc_macro2::{
     Span as Span2,
     TokenStream as TokenStream2,
 };
-use quote::quote;
+use quote::{
+    format_ident,
+    quote,
+};
 use syn::{
-    GenericArgument,
     Ident,
-    PathArguments,
-    Type,
+    Member,
+};
+
+use super::{
+    docs::make_docs,
+    error_enum::{
+        make_check_statements,
+        make_error_enum_ident,
+    },
+    helper_methods::make_helper_methods,
+    setter_configs::{
+        SetterConfigs,
+        make_field_name,
+    },
+    setter_kind::SetterKind,
 };
 
-use super::setter_configs::SetterConfigs;
+const TRY_INTO_TYPE_PARAM: &str = "TryIntoValue";
+const FROM_STR_SUFFIX: &str = "str";
 
 pub fn make_setter_methods(
+    struct_ident: &Ident,
     setter_configs: SetterConfigs,
 ) -> Vec<TokenStream2> {
     let mut setter_methods: Vec<TokenStream2> = Vec::new();
 
-    for (field, field_setter_configs) in setter_configs.iter() {
-        let field_name = &field.ident;
+    for (member, field, field_setter_configs) in setter_configs.iter() {
         let field_type = &field.ty;
+        let field_name = make_field_name(member);
+        // Setters of unnamed fields take `value`, since fields have
+        // no names to use for params.
+        let param = match member {
+            Member::Named(field_ident) => field_ident.clone(),
+            Member::Unnamed(_) => format_ident!("value"),
+        };
         let span = Span2::call_site();
 
         for field_setter_config in field_setter_configs {
-            let method_name = Ident::new(field_setter_config.name(), span);
+            let method_name =
+                format_ident!("{}", field_setter_config.name(), span = span);
             let method_visibility = field_setter_config.visibility();
             let with_into = field_setter_config.with_into();
+            let with_try_into = field_setter_config.with_try_into();
+            let constness =
+                field_setter_config.const_fn().then(|| quote! { const });
 
-            let option_inner_type = extract_option_inner_type(field_type);
+            // Only the outer layer of nested options is stripped, so
+            // setters of `Option<Option<T>>` take `Option<T>`.
+            let option_inner_type = field_setter_config
+                .option_inner_type()
+                .filter(|_| field_setter_config.strip_option());
+            let value_type = option_inner_type.unwrap_or(field_type);
+            let try_into_type = format_ident!("{TRY_INTO_TYPE_PARAM}");
+            let (generics, param_type, value) = match (
+                field_setter_config.sequence_item_type(),
+                with_try_into,
+                with_into,
+            ) {
+                // Setters of sequences with `each` param take items.
+                (Some(item_type), _, true) => (
+                    None,
+                    quote! { impl IntoIterator<Item = impl Into<#item_type>> },
+                    quote! { #param.into_iter().map(Into::into).collect() },
+                ),
+                (Some(item_type), _, false) => (
+                    None,
+                    quote! { impl IntoIterator<Item = #item_type> },
+                    quote! { #param.into_iter().collect() },
+                ),
+                (None, true, _) => (
+                    Some(quote! {
+                        <#try_into_type: ::core::convert::TryInto<#value_type>>
+                    }),
+                    quote! { #try_into_type },
+                    quote! { ::core::convert::TryInto::try_into(#param)? },
+                ),
+                (None, false, true) => (
+                    None,
+                    quote! { impl Into<#value_type> },
+                    quote! { #param.into() },
+                ),
+                (None, false, false) => {
+                    (None, quote! { #value_type }, quote! { #param })
+                }
+            };
+            let wrap_value = |value: TokenStream2| match option_inner_type {
+                Some(_) => quote! { Some(#value) },
+                None => value,
+            };
+
+            let validator = field_setter_config.validator();
+            let checks = field_setter_config.checks();
+
+            let setter_method =
+                match (validator, checks.is_empty(), with_try_into) {
+                    (None, true, false) => {
+                        let value = wrap_value(value);
+                        let docs = make_docs(
+                            format!("Sets the value of `{field_name}`."),
+                            field,
+                            field_setter_config.doc(),
+                        );
 
-            let setter_method = match option_inner_type {
-                Some(inner_type) => {
-                    if with_into {
-                        quote! {
-                            #method_visibility fn #method_name(
-                                &mut self,
-                                #field_name: impl Into<#inner_type>,
-                            ) {
-                                self.#field_name = Some(#field_name.into());
-                            }
-                        }
-                    } else {
                         quote! {
-                            #method_visibility fn #method_name(
+                            #docs
+                            #method_visibility #constness fn #method_name(
                                 &mut self,
-                                #field_name: #inner_type,
+                                #param: #param_type,
                             ) {
-                                self.#field_name = Some(#field_name);
+                                self.#member = #value;
                             }
                         }
                     }
-                }
-                None => {
-                    if with_into {
-                        quote! {
-                            #method_visibility fn #method_name(
-                                &mut self,
-                                #field_name: impl Into<#field_type>,
-                            ) {
-                                self.#field_name = #field_name.into();
+                    _ => {
+                        // Validators require error type, while declarative
+                        // checks can't be set with them, so errors of checks
+                        // are always variants of the generated enum.
+                        let error_type = if with_try_into {
+                            quote! {
+                                <#try_into_type as ::core::convert::TryInto<
+                                    #value_type,
+                                >>::Error
                             }
-                        }
-                    } else {
+                        } else if let Some(error_type) =
+                            field_setter_config.error_type()
+                        {
+                            quote! { #error_type }
+                        } else {
+                            let error_enum_ident = make_error_enum_ident(
+                                struct_ident,
+                                SetterKind::Basic,
+                            );
+                            quote! { #error_enum_ident }
+                        };
+                        let validation = validator.map(|validator| {
+                            quote! { #validator(&#param)?; }
+                        });
+                        let check_statements = make_check_statements(
+                            struct_ident,
+                            member,
+                            field_setter_config,
+                            &param,
+                            SetterKind::Basic,
+                        );
+                        let assigned_value = wrap_value(quote! { #param });
+                        let lead = match validator {
+                            Some(validator) => format!(
+                                "Sets the value of `{field_name}`, if it \
+                            passes validation by `{}`.",
+                                quote! { #validator },
+                            ),
+                            None if with_try_into => format!(
+                                "Sets the value of `{field_name}`, if it can \
+                            be converted by `TryInto`."
+                            ),
+                            None => format!(
+                                "Sets the value of `{field_name}`, if it \
+                            passes the checks."
+                            ),
+                        };
+                        let docs =
+                            make_docs(lead, field, field_setter_config.doc());
+
                         quote! {
-                            #method_visibility fn #method_name(
+                            #docs
+                            #method_visibility fn #method_name #generics(
                                 &mut self,
-                                #field_name: #field_type,
-                            ) {
-                                self.#field_name = #field_name;
+                                #param: #param_type,
+                            ) -> ::core::result::Result<(), #error_type> {
+                                let #param: #value_type = #value;
+                                #validation
+                                #check_statements
+                                self.#member = #assigned_value;
+                                ::core::result::Result::Ok(())
                             }
                         }
                     }
-                }
-            };
+                };
             setter_methods.push(setter_method);
-        }
-    }
 
-    setter_methods
-}
-
-fn extract_option_inner_type(field_type: &Type) -> Option<&Type> {
-    if let Type::Path(type_path) = field_type {
-        if type_path.path.segments.is_empty() {
-            return None;
-        }
+            if field_setter_config.with_from_str() {
+                let method_name = format_ident!(
+                    "{}_{FROM_STR_SUFFIX}",
+                    field_setter_config.name(),
+                    span = span,
+                );
+                let assigned_value = wrap_value(quote! { #param });
+                let docs = make_docs(
+                    format!(
+                        "Sets the value of `{field_name}` parsed from \
+                        string by `FromStr`."
+                    ),
+                    field,
+                    None,
+                );
 
-        if type_path.path.segments[0].ident == "Option" {
-            if let PathArguments::AngleBracketed(args) =
-                &type_path.path.segments[0].arguments
-            {
-                if let Some(GenericArgument::Type(inner_type)) =
-                    args.args.first()
-                {
-                    return Some(inner_type);
-                }
+                setter_methods.push(quote! {
+                    #docs
+                    #method_visibility fn #method_name(
+                        &mut self,
+                        #param: &str,
+                    ) -> ::core::result::Result<
+                        (),
+                        <#value_type as ::core::str::FromStr>::Err,
+                    > {
+                        let #param: #value_type =
+                            ::core::str::FromStr::from_str(#param)?;
+                        self.#member = #assigned_value;
+                        ::core::result::Result::Ok(())
+                    }
+                });
             }
+            setter_methods.extend(make_helper_methods(
+                member,
+                field,
+                field_setter_config,
+            ));
         }
     }
-    None
+
+    setter_methods
 }
@@ -0,0 +1,312 @@
+use proc_macro2::{
+    Span as Span2,
+    TokenStream as TokenStream2,
+};
+use quote::{
+    format_ident,
+    quote,
+};
+use syn::{
+    Field,
+    Member,
+    Type,
+};
+
+use super::{
+    docs::make_docs,
+    setter_configs::{
+        KeyedCollection,
+        SetterConfig,
+        make_field_name,
+    },
+    setter_methods::make_receiver,
+    struct_config::Receiver,
+};
+
+const WITHOUT_PREFIX: &str = "without";
+const EXTEND_PREFIX: &str = "with";
+const EXTEND_SUFFIX: &str = "items";
+const INSERT_PREFIX: &str = "with";
+const MAP_PREFIX: &str = "map";
+
+/// Makes helper methods of setter, which are enabled by its config.
+/// They share visibility of the setter and are named after its
+/// suffix.
+pub fn make_helper_methods(
+    member: &Member,
+    field: &Field,
+    field_setter_config: &SetterConfig,
+) -> Vec<TokenStream2> {
+    let mut helper_methods: Vec<TokenStream2> = Vec::new();
+
+    if field_setter_config.option_helpers() {
+        helper_methods.extend(make_option_helper_methods(
+            member,
+            field,
+            field_setter_config,
+        ));
+    }
+    if field_setter_config.update() {
+        helper_methods.push(make_map_method(
+            member,
+            field,
+            field_setter_config,
+        ));
+    }
+    if let Some(each) = field_setter_config.each() {
+        helper_methods.extend(make_sequence_helper_methods(
+            member,
+            field,
+            field_setter_config,
+            each,
+        ));
+    }
+    if let Some(singular) = field_setter_config.singular() {
+        helper_methods.extend(make_keyed_collection_helper_methods(
+            member,
+            field,
+            field_setter_config,
+            singular,
+        ));
+    }
+
+    helper_methods
+}
+
+/// Makes `without_<suffix>` method of `Option` field.
+fn make_option_helper_methods(
+    member: &Member,
+    field: &Field,
+    field_setter_config: &SetterConfig,
+) -> Vec<TokenStream2> {
+    if field_setter_config.option_inner_type().is_none() {
+        return Vec::new();
+    }
+    let field_name = make_field_name(member);
+    let suffix = field_setter_config.suffix();
+    let method_visibility = field_setter_config.visibility();
+    let (receiver, return_type, must_use) =
+        make_receiver(field_setter_config.receiver());
+    let span = Span2::call_site();
+
+    let without_name = format_ident!("{WITHOUT_PREFIX}_{suffix}", span = span);
+    let without_docs = make_docs(
+        format!("Returns `self` with `{field_name}` set to `None`."),
+        field,
+        None,
+    );
+
+    vec![quote! {
+        #without_docs
+        #must_use
+        #method_visibility fn #without_name(#receiver) -> #return_type {
+            self.#member = None;
+            self
+        }
+    }]
+}
+
+/// Makes `map_<suffix>` method, which returns `self` with the value of
+/// field changed by a closure. Closure gets inner value of stripped
+/// `Option` field, so it's called only when the field is `Some`.
+/// Values cannot be moved out of `&mut self`, so closure of method
+/// with `ref_mut` receiver changes them in place.
+fn make_map_method(
+    member: &Member,
+    field: &Field,
+    field_setter_config: &SetterConfig,
+) -> TokenStream2 {
+    let value_type = field_setter_config.value_type(&field.ty);
+    let field_name = make_field_name(member);
+    let suffix = field_setter_config.suffix();
+    let method_visibility = field_setter_config.visibility();
+    let receiver_kind = field_setter_config.receiver();
+    let (receiver, return_type, must_use) = make_receiver(receiver_kind);
+    let span = Span2::call_site();
+
+    let map_name = format_ident!("{MAP_PREFIX}_{suffix}", span = span);
+    let is_option = field_setter_config.strip_option()
+        && field_setter_config.option_inner_type().is_some();
+    let (closure_type, map) = match (receiver_kind, is_option) {
+        (Receiver::Owned, false) => (
+            quote! { impl FnOnce(#value_type) -> #value_type },
+            quote! { self.#member = f(self.#member); },
+        ),
+        (Receiver::Owned, true) => (
+            quote! { impl FnOnce(#value_type) -> #value_type },
+            quote! { self.#member = self.#member.map(f); },
+        ),
+        (Receiver::RefMut, false) => (
+            quote! { impl FnOnce(&mut #value_type) },
+            quote! { f(&mut self.#member); },
+        ),
+        (Receiver::RefMut, true) => (
+            quote! { impl FnOnce(&mut #value_type) },
+            quote! {
+                if let Some(value) = &mut self.#member {
+                    f(value);
+                }
+            },
+        ),
+    };
+    let map_docs = make_docs(
+        format!(
+            "Returns `self` with the value of `{field_name}` changed by `f`."
+        ),
+        field,
+        None,
+    );
+
+    quote! {
+        #map_docs
+        #must_use
+        #method_visibility fn #map_name(
+            #receiver,
+            f: #closure_type,
+        ) -> #return_type {
+            #map
+            self
+        }
+    }
+}
+
+/// Makes `<each>` and `with_<suffix>_items` methods of sequence field,
+/// which push a single item and items of iterator into it.
+fn make_sequence_helper_methods(
+    member: &Member,
+    field: &Field,
+    field_setter_config: &SetterConfig,
+    each: &str,
+) -> Vec<TokenStream2> {
+    let Some(item_type) = field_setter_config.sequence_item_type() else {
+        return Vec::new();
+    };
+    let field_name = make_field_name(member);
+    let suffix = field_setter_config.suffix();
+    let method_visibility = field_setter_config.visibility();
+    let (receiver, return_type, must_use) =
+        make_receiver(field_setter_config.receiver());
+    let span = Span2::call_site();
+
+    let (item_param_type, item, items) = if field_setter_config.with_into() {
+        (
+            quote! { impl Into<#item_type> },
+            quote! { item.into() },
+            quote! { iter.into_iter().map(Into::into) },
+        )
+    } else {
+        (quote! { #item_type }, quote! { item }, quote! { iter })
+    };
+
+    let push_name = format_ident!("{}", each, span = span);
+    let push_docs = make_docs(
+        format!("Returns `self` with an item pushed into `{field_name}`."),
+        field,
+        None,
+    );
+    let extend_name =
+        format_ident!("{EXTEND_PREFIX}_{suffix}_{EXTEND_SUFFIX}", span = span);
+    let extend_docs = make_docs(
+        format!(
+            "Returns `self` with `{field_name}` extended with items of \
+            `iter`."
+        ),
+        field,
+        None,
+    );
+
+    vec![
+        quote! {
+            #push_docs
+            #must_use
+            #method_visibility fn #push_name(
+                #receiver,
+                item: #item_param_type,
+            ) -> #return_type {
+                self.#member.extend(::core::iter::once(#item));
+                self
+            }
+        },
+        quote! {
+            #extend_docs
+            #must_use
+            #method_visibility fn #extend_name(
+                #receiver,
+                iter: impl IntoIterator<Item = #item_param_type>,
+            ) -> #return_type {
+                self.#member.extend(#items);
+                self
+            }
+        },
+    ]
+}
+
+/// Makes `with_<singular>` method of map or set field, which
+/// returns `self` with an entry inserted into the field.
+fn make_keyed_collection_helper_methods(
+    member: &Member,
+    field: &Field,
+    field_setter_config: &SetterConfig,
+    singular: &str,
+) -> Vec<TokenStream2> {
+    let Some(keyed_collection) = field_setter_config.keyed_collection() else {
+        return Vec::new();
+    };
+    let field_name = make_field_name(member);
+    let method_visibility = field_setter_config.visibility();
+    let (receiver, return_type, must_use) =
+        make_receiver(field_setter_config.receiver());
+    let with_into = field_setter_config.with_into();
+    let span = Span2::call_site();
+    let param = |name: &str, param_type: &Type| {
+        let name = format_ident!("{name}");
+        if with_into {
+            (
+                quote! { #name: impl Into<#param_type> },
+                quote! { #name.into() },
+            )
+        } else {
+            (quote! { #name: #param_type }, quote! { #name })
+        }
+    };
+
+    let insert_name = format_ident!("{INSERT_PREFIX}_{singular}", span = span);
+
+    let (params, insert) = match keyed_collection {
+        KeyedCollection::Map {
+            key_type,
+            value_type,
+        } => {
+            let (key_param, key) = param("key", key_type);
+            let (value_param, value) = param("value", value_type);
+            (
+                quote! { #key_param, #value_param },
+                quote! { self.#member.insert(#key, #value); },
+            )
+        }
+        KeyedCollection::Set { item_type } => {
+            let (item_param, item) = param("item", item_type);
+            (
+                quote! { #item_param },
+                quote! { self.#member.insert(#item); },
+            )
+        }
+    };
+    let insert_docs = make_docs(
+        format!("Returns `self` with an entry inserted into `{field_name}`."),
+        field,
+        None,
+    );
+
+    vec![quote! {
+        #insert_docs
+        #must_use
+        #method_visibility fn #insert_name(
+            #receiver,
+            #params,
+        ) -> #return_type {
+            #insert
+            self
+        }
+    }]
+}
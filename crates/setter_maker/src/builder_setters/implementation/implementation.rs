@@ -1,22 +1,179 @@
 use proc_macro::TokenStream as TokenStream1;
+use proc_macro2::TokenStream as TokenStream2;
 use quote::quote;
-use syn::ItemStruct;
+use syn::{
+    Error,
+    ItemStruct,
+    Member,
+    MetaNameValue,
+    Token,
+    Type,
+    punctuated::Punctuated,
+};
 
 use super::{
+    error_enum::make_error_enum,
     impl_block::make_impl_block,
-    setter_configs::make_setter_configs,
+    setter_configs::{
+        make_member,
+        make_setter_configs,
+    },
+    setter_kind::SetterKind,
     setter_methods::make_setter_methods,
+    struct_config::{
+        StructConfig,
+        make_struct_config,
+    },
+    typestate_builder::{
+        extract_required_members,
+        make_typestate_builder,
+    },
 };
 
-pub fn do_make_builder_setters(mut item: ItemStruct) -> TokenStream1 {
-    let setter_configs = make_setter_configs(&mut item.fields);
-    let setter_methods = make_setter_methods(setter_configs);
-    let impl_block =
-        make_impl_block(&item.ident, &item.generics, setter_methods);
+pub fn do_make_builder_setters(
+    params: Punctuated<MetaNameValue, Token![,]>,
+    item: ItemStruct,
+) -> TokenStream1 {
+    make_builder_setters(params, item).into()
+}
+
+fn make_builder_setters(
+    params: Punctuated<MetaNameValue, Token![,]>,
+    mut item: ItemStruct,
+) -> TokenStream2 {
+    let mut errors: Vec<Error> = Vec::new();
+
+    // Config attributes must be removed from fields even if params
+    // of the macro are invalid, so the default config is used then.
+    let struct_config = make_struct_config(params, SetterKind::Builder)
+        .unwrap_or_else(|error| {
+            errors.push(error);
+            StructConfig::new(SetterKind::Builder)
+        });
+    let required_members =
+        extract_required_members(&mut item.fields, struct_config.typestate())
+            .unwrap_or_else(|error| {
+                errors.push(error);
+                Vec::new()
+            });
+    let field_types: Vec<(Member, Type)> = item
+        .fields
+        .iter()
+        .enumerate()
+        .map(|(index, field)| (make_member(field, index), field.ty.clone()))
+        .collect();
+    let impl_block = match make_setter_configs(
+        &mut item.fields,
+        &item.generics,
+        &struct_config,
+    ) {
+        // Typestate builders replace setters of the struct itself.
+        Ok(setter_configs) if struct_config.typestate() => {
+            make_typestate_builder(
+                &item.ident,
+                &item.vis,
+                &item.generics,
+                &field_types,
+                &required_members,
+                &setter_configs,
+            )
+            .unwrap_or_else(|error| {
+                errors.push(error);
+                TokenStream2::new()
+            })
+        }
+        Ok(setter_configs) => {
+            let error_enum = make_error_enum(
+                &item.ident,
+                &item.vis,
+                &setter_configs,
+                SetterKind::Builder,
+            );
+            let setter_methods = make_setter_methods(
+                &item.ident,
+                &item.generics,
+                setter_configs,
+            );
+            let impl_block =
+                make_impl_block(&item.ident, &item.generics, setter_methods);
+            quote! {
+                #error_enum
+                #impl_block
+            }
+        }
+        Err(error) => {
+            errors.push(error);
+            TokenStream2::new()
+        }
+    };
+    let errors = errors.iter().map(Error::to_compile_error);
 
     quote! {
         #item
         #impl_block
+        #(#errors)*
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use proc_macro2::TokenStream as TokenStream2;
+    use syn::{
+        File,
+        ImplItem,
+        Item,
+        ItemStruct,
+        parse_quote,
+        punctuated::Punctuated,
+    };
+
+    use super::make_builder_setters;
+
+    /// Returns names of methods of impl blocks in order of expansion.
+    fn collect_method_names(expansion: TokenStream2) -> Vec<String> {
+        let file: File = syn::parse2(expansion).unwrap();
+        file.items
+            .iter()
+            .filter_map(|item| match item {
+                Item::Impl(item_impl) => Some(&item_impl.items),
+                _ => None,
+            })
+            .flatten()
+            .filter_map(|impl_item| match impl_item {
+                ImplItem::Fn(method) => Some(method.sig.ident.to_string()),
+                _ => None,
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_methods_order() {
+        let item: ItemStruct = parse_quote! {
+            struct Foo {
+                #[builder_setter(name = "with_c_first")]
+                #[builder_setter(name = "with_c_second", option_helpers = true)]
+                c: Option<u8>,
+                a: u8,
+                #[builder_setter(each = "with_b_item")]
+                b: Vec<u8>,
+            }
+        };
+
+        let method_names = collect_method_names(make_builder_setters(
+            Punctuated::new(),
+            item,
+        ));
+        assert_eq!(
+            method_names,
+            [
+                "with_c_first",
+                "with_c_second",
+                "without_c",
+                "with_a",
+                "with_b",
+                "with_b_item",
+                "with_b_items",
+            ]
+        );
     }
-    .into()
 }
@@ -1,110 +1,403 @@
+use proc_macro2::{
+    Group,
+    TokenTree,
+};
 use proc_macro2::{
     Span as Span2,
     TokenStream as TokenStream2,
 };
-use quote::quote;
+use quote::ToTokens;
+use quote::{
+    format_ident,
+    quote,
+};
 use syn::{
-    GenericArgument,
+    GenericParam,
+    Generics,
     Ident,
-    PathArguments,
-    Type,
+    Member,
+    Visibility,
+};
+
+use super::{
+    docs::make_docs,
+    error_enum::{
+        make_check_statements,
+        make_error_enum_ident,
+    },
+    helper_methods::make_helper_methods,
+    setter_configs::{
+        SetterConfigs,
+        TypeChange,
+        contains_ident,
+        make_field_name,
+    },
+    setter_kind::SetterKind,
+    struct_config::Receiver,
 };
 
-use super::setter_configs::SetterConfigs;
+const TRY_INTO_TYPE_PARAM: &str = "TryIntoValue";
+const FROM_STR_SUFFIX: &str = "str";
+const NEW_TYPE_PARAM: &str = "U";
 
 pub fn make_setter_methods(
+    struct_ident: &Ident,
+    struct_generics: &Generics,
     setter_configs: SetterConfigs,
 ) -> Vec<TokenStream2> {
     let mut setter_methods: Vec<TokenStream2> = Vec::new();
 
-    for (field, field_setter_configs) in setter_configs.iter() {
-        let field_name = &field.ident;
+    for (member, field, field_setter_configs) in setter_configs.iter() {
         let field_type = &field.ty;
+        let field_name = make_field_name(member);
+        // Setters of unnamed fields take `value`, since fields have
+        // no names to use for params.
+        let param = match member {
+            Member::Named(field_ident) => field_ident.clone(),
+            Member::Unnamed(_) => format_ident!("value"),
+        };
         let span = Span2::call_site();
 
         for field_setter_config in field_setter_configs {
-            let method_name = Ident::new(field_setter_config.name(), span);
+            let method_name =
+                format_ident!("{}", field_setter_config.name(), span = span);
             let method_visibility = field_setter_config.visibility();
             let with_into = field_setter_config.with_into();
+            let with_try_into = field_setter_config.with_try_into();
+            let constness =
+                field_setter_config.const_fn().then(|| quote! { const });
+            let (receiver, return_type, must_use) =
+                make_receiver(field_setter_config.receiver());
 
-            let option_inner_type = extract_option_inner_type(field_type);
+            if let Some(type_change) = field_setter_config.type_change() {
+                let docs = make_docs(
+                    format!(
+                        "Returns the struct with the value of \
+                        `{field_name}` set, changing its type parameter \
+                        `{}`.",
+                        type_change.type_param(),
+                    ),
+                    field,
+                    field_setter_config.doc(),
+                );
+                setter_methods.push(make_type_changing_method(
+                    struct_ident,
+                    struct_generics,
+                    member,
+                    type_change,
+                    &method_name,
+                    method_visibility,
+                    &param,
+                    docs,
+                ));
+                setter_methods.extend(make_helper_methods(
+                    member,
+                    field,
+                    field_setter_config,
+                ));
+                continue;
+            }
+
+            // Only the outer layer of nested options is stripped, so
+            // setters of `Option<Option<T>>` take `Option<T>`.
+            let option_inner_type = field_setter_config
+                .option_inner_type()
+                .filter(|_| field_setter_config.strip_option());
+            let value_type = option_inner_type.unwrap_or(field_type);
+            let try_into_type = format_ident!("{TRY_INTO_TYPE_PARAM}");
+            let (generics, param_type, value) = match (
+                field_setter_config.sequence_item_type(),
+                with_try_into,
+                with_into,
+            ) {
+                // Setters of sequences with `each` param take items.
+                (Some(item_type), _, true) => (
+                    None,
+                    quote! { impl IntoIterator<Item = impl Into<#item_type>> },
+                    quote! { #param.into_iter().map(Into::into).collect() },
+                ),
+                (Some(item_type), _, false) => (
+                    None,
+                    quote! { impl IntoIterator<Item = #item_type> },
+                    quote! { #param.into_iter().collect() },
+                ),
+                (None, true, _) => (
+                    Some(quote! {
+                        <#try_into_type: ::core::convert::TryInto<#value_type>>
+                    }),
+                    quote! { #try_into_type },
+                    quote! { ::core::convert::TryInto::try_into(#param)? },
+                ),
+                (None, false, true) => (
+                    None,
+                    quote! { impl Into<#value_type> },
+                    quote! { #param.into() },
+                ),
+                (None, false, false) => {
+                    (None, quote! { #value_type }, quote! { #param })
+                }
+            };
+            let wrap_value = |value: TokenStream2| match option_inner_type {
+                Some(_) => quote! { Some(#value) },
+                None => value,
+            };
+
+            let validator = field_setter_config.validator();
+            let checks = field_setter_config.checks();
+
+            let setter_method =
+                match (validator, checks.is_empty(), with_try_into) {
+                    (None, true, false) => {
+                        let value = wrap_value(value);
+                        let docs = make_docs(
+                            format!(
+                                "Returns `self` with the value of \
+                            `{field_name}` set."
+                            ),
+                            field,
+                            field_setter_config.doc(),
+                        );
 
-            let setter_method = match option_inner_type {
-                Some(inner_type) => {
-                    if with_into {
-                        quote! {
-                            #[must_use]
-                            #method_visibility fn #method_name(
-                                mut self,
-                                #field_name: impl Into<#inner_type>,
-                            ) -> Self {
-                                self.#field_name = Some(#field_name.into());
-                                self
-                            }
-                        }
-                    } else {
                         quote! {
-                            #[must_use]
-                            #method_visibility fn #method_name(
-                                mut self,
-                                #field_name: #inner_type,
-                            ) -> Self {
-                                self.#field_name = Some(#field_name);
+                            #docs
+                            #must_use
+                            #method_visibility #constness fn #method_name(
+                                #receiver,
+                                #param: #param_type,
+                            ) -> #return_type {
+                                self.#member = #value;
                                 self
                             }
                         }
                     }
-                }
-                None => {
-                    if with_into {
-                        quote! {
-                            #[must_use]
-                            #method_visibility fn #method_name(
-                                mut self,
-                                #field_name: impl Into<#field_type>,
-                            ) -> Self {
-                                self.#field_name = #field_name.into();
-                                self
+                    _ => {
+                        // Validators require error type, while declarative
+                        // checks can't be set with them, so errors of checks
+                        // are always variants of the generated enum.
+                        let error_type = if with_try_into {
+                            quote! {
+                                <#try_into_type as ::core::convert::TryInto<
+                                    #value_type,
+                                >>::Error
                             }
-                        }
-                    } else {
+                        } else if let Some(error_type) =
+                            field_setter_config.error_type()
+                        {
+                            quote! { #error_type }
+                        } else {
+                            let error_enum_ident = make_error_enum_ident(
+                                struct_ident,
+                                SetterKind::Builder,
+                            );
+                            quote! { #error_enum_ident }
+                        };
+                        let validation = validator.map(|validator| {
+                            quote! { #validator(&#param)?; }
+                        });
+                        let check_statements = make_check_statements(
+                            struct_ident,
+                            member,
+                            field_setter_config,
+                            &param,
+                            SetterKind::Builder,
+                        );
+                        let assigned_value = wrap_value(quote! { #param });
+                        let lead = match validator {
+                            Some(validator) => format!(
+                                "Returns `self` with the value of \
+                            `{field_name}` set, if it passes validation \
+                            by `{}`.",
+                                quote! { #validator },
+                            ),
+                            None if with_try_into => format!(
+                                "Returns `self` with the value of \
+                            `{field_name}` set, if it can be converted by \
+                            `TryInto`."
+                            ),
+                            None => format!(
+                                "Returns `self` with the value of \
+                            `{field_name}` set, if it passes the checks."
+                            ),
+                        };
+                        let docs =
+                            make_docs(lead, field, field_setter_config.doc());
+
+                        // Result is already `#[must_use]`.
                         quote! {
-                            #[must_use]
-                            #method_visibility fn #method_name(
-                                mut self,
-                                #field_name: #field_type,
-                            ) -> Self {
-                                self.#field_name = #field_name;
-                                self
+                            #docs
+                            #method_visibility fn #method_name #generics(
+                                #receiver,
+                                #param: #param_type,
+                            ) -> ::core::result::Result<
+                                #return_type,
+                                #error_type,
+                            > {
+                                let #param: #value_type = #value;
+                                #validation
+                                #check_statements
+                                self.#member = #assigned_value;
+                                ::core::result::Result::Ok(self)
                             }
                         }
                     }
-                }
-            };
+                };
             setter_methods.push(setter_method);
+
+            if field_setter_config.with_from_str() {
+                let method_name = format_ident!(
+                    "{}_{FROM_STR_SUFFIX}",
+                    field_setter_config.name(),
+                    span = span,
+                );
+                let assigned_value = wrap_value(quote! { #param });
+                let docs = make_docs(
+                    format!(
+                        "Returns `self` with the value of `{field_name}` \
+                        parsed from string by `FromStr`."
+                    ),
+                    field,
+                    None,
+                );
+
+                // Result is already `#[must_use]`.
+                setter_methods.push(quote! {
+                    #docs
+                    #method_visibility fn #method_name(
+                        #receiver,
+                        #param: &str,
+                    ) -> ::core::result::Result<
+                        #return_type,
+                        <#value_type as ::core::str::FromStr>::Err,
+                    > {
+                        let #param: #value_type =
+                            ::core::str::FromStr::from_str(#param)?;
+                        self.#member = #assigned_value;
+                        ::core::result::Result::Ok(self)
+                    }
+                });
+            }
+            setter_methods.extend(make_helper_methods(
+                member,
+                field,
+                field_setter_config,
+            ));
         }
     }
 
     setter_methods
 }
 
-fn extract_option_inner_type(field_type: &Type) -> Option<&Type> {
-    if let Type::Path(type_path) = field_type {
-        if type_path.path.segments.is_empty() {
+/// Makes setter, which moves fields into the struct with another type
+/// in place of the type parameter of the field. Bounds of the type
+/// parameter and where predicates mentioning it are required for the
+/// new type too.
+#[allow(clippy::too_many_arguments)]
+fn make_type_changing_method(
+    struct_ident: &Ident,
+    struct_generics: &Generics,
+    member: &Member,
+    type_change: &TypeChange,
+    method_name: &Ident,
+    method_visibility: &Visibility,
+    param: &Ident,
+    docs: TokenStream2,
+) -> TokenStream2 {
+    let type_param = type_change.type_param();
+    let generics_tokens = struct_generics.to_token_stream();
+    let new_type_param = (0..)
+        .map(|index| match index {
+            0 => format_ident!("{NEW_TYPE_PARAM}"),
+            index => format_ident!("{NEW_TYPE_PARAM}{index}"),
+        })
+        .find(|ident| !contains_ident(generics_tokens.clone(), ident))
+        .expect("Some identifier is not used by generics.");
+    let replace = |tokens: TokenStream2| {
+        replace_ident(tokens, type_param, &new_type_param)
+    };
+
+    let type_args = struct_generics.params.iter().map(|param| match param {
+        GenericParam::Lifetime(param) => param.lifetime.to_token_stream(),
+        GenericParam::Type(param) => replace(param.ident.to_token_stream()),
+        GenericParam::Const(param) => param.ident.to_token_stream(),
+    });
+    let param_bounds = struct_generics.params.iter().filter_map(|param| {
+        let GenericParam::Type(param) = param else {
             return None;
-        }
+        };
+        let bounds = param.bounds.to_token_stream();
+        (!param.bounds.is_empty()
+            && (param.ident == *type_param
+                || contains_ident(bounds.clone(), type_param)))
+        .then(|| {
+            let ident = &param.ident;
+            replace(quote! { #ident: #bounds })
+        })
+    });
+    let predicates = struct_generics
+        .where_clause
+        .iter()
+        .flat_map(|where_clause| &where_clause.predicates)
+        .map(ToTokens::to_token_stream)
+        .filter(|predicate| contains_ident(predicate.clone(), type_param))
+        .map(replace);
+    let other_members = type_change.other_members();
 
-        if type_path.path.segments[0].ident == "Option" {
-            if let PathArguments::AngleBracketed(args) =
-                &type_path.path.segments[0].arguments
-            {
-                if let Some(GenericArgument::Type(inner_type)) =
-                    args.args.first()
-                {
-                    return Some(inner_type);
-                }
+    quote! {
+        #docs
+        #[must_use]
+        #method_visibility fn #method_name<#new_type_param>(
+            self,
+            #param: #new_type_param,
+        ) -> #struct_ident<#(#type_args),*>
+        where
+            #(#param_bounds,)*
+            #(#predicates,)*
+        {
+            #struct_ident {
+                #member: #param,
+                #(#other_members: self.#other_members,)*
             }
         }
     }
-    None
+}
+
+fn replace_ident(
+    tokens: TokenStream2,
+    from: &Ident,
+    to: &Ident,
+) -> TokenStream2 {
+    tokens
+        .into_iter()
+        .map(|token| match token {
+            TokenTree::Ident(ident) if ident == *from => {
+                TokenTree::Ident(Ident::new(&to.to_string(), ident.span()))
+            }
+            TokenTree::Group(group) => {
+                let mut replaced = Group::new(
+                    group.delimiter(),
+                    replace_ident(group.stream(), from, to),
+                );
+                replaced.set_span(group.span());
+                TokenTree::Group(replaced)
+            }
+            token => token,
+        })
+        .collect()
+}
+
+/// Makes receiver and return type of builder method. Methods taking
+/// `self` by value are `#[must_use]`, since the struct is dropped
+/// otherwise, while methods taking `&mut self` return the reference
+/// only for chaining.
+pub fn make_receiver(
+    receiver: Receiver,
+) -> (TokenStream2, TokenStream2, Option<TokenStream2>) {
+    match receiver {
+        Receiver::Owned => (
+            quote! { mut self },
+            quote! { Self },
+            Some(quote! { #[must_use] }),
+        ),
+        Receiver::RefMut => (quote! { &mut self }, quote! { &mut Self }, None),
+    }
 }
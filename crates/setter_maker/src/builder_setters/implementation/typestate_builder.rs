@@ -0,0 +1,525 @@
+use proc_macro2::{
+    Span as Span2,
+    TokenStream as TokenStream2,
+};
+use quote::{
+    ToTokens,
+    format_ident,
+    quote,
+};
+use syn::{
+    Error,
+    Field,
+    Fields,
+    GenericParam,
+    Generics,
+    Ident,
+    Index,
+    Member,
+    Result,
+    Type,
+    TypeParam,
+    Visibility,
+    ext::IdentExt,
+};
+
+use super::{
+    docs::make_docs,
+    error_enum::make_pascal_case_name,
+    setter_configs::{
+        SetterConfig,
+        SetterConfigs,
+        combine_errors,
+        contains_ident,
+        make_field_name,
+        make_member,
+    },
+    struct_config::Receiver,
+};
+
+const REQUIRED_ATTRIBUTE: &str = "required";
+const BUILDER_SUFFIX: &str = "Builder";
+const STATE_SUFFIX: &str = "State";
+const BUILDER_METHOD: &str = "builder";
+const BUILD_METHOD: &str = "build";
+
+/// Field of typestate builder. Values of required fields are kept in
+/// state type parameters, which are `()` until the field is set and
+/// `(T,)` afterwards.
+struct BuilderField<'a> {
+    member: Member,
+    field_type: &'a Type,
+    state: Option<Ident>,
+}
+
+/// Extracts members of fields marked by `#[required]` attribute and
+/// removes the attributes, so they don't reach the compiler. They are
+/// errors, unless the struct has a typestate builder.
+pub fn extract_required_members(
+    fields: &mut Fields,
+    typestate: bool,
+) -> Result<Vec<Member>> {
+    let mut required_members: Vec<Member> = Vec::new();
+    let mut errors: Vec<Error> = Vec::new();
+
+    for (index, field) in fields.iter_mut().enumerate() {
+        let member = make_member(field, index);
+
+        field.attrs.retain(|attr| {
+            if !attr.path().is_ident(REQUIRED_ATTRIBUTE) {
+                return true;
+            }
+            if !typestate {
+                errors.push(Error::new_spanned(
+                    attr,
+                    format!(
+                        "'{REQUIRED_ATTRIBUTE}' attribute is supported only \
+                        by typestate builders, which are made with \
+                        `typestate = true`."
+                    ),
+                ));
+            } else if let Err(error) = attr.meta.require_path_only() {
+                errors.push(error);
+            } else if required_members.contains(&member) {
+                errors.push(Error::new_spanned(
+                    attr,
+                    format!("Duplicate '{REQUIRED_ATTRIBUTE}' attribute."),
+                ));
+            } else {
+                required_members.push(member.clone());
+            }
+            false
+        });
+    }
+
+    combine_errors(errors)?;
+    Ok(required_members)
+}
+
+/// Makes `<Struct>Builder`, whose type parameters track which
+/// required fields are set, so `build` exists only when all of them
+/// are. Setters move the builder into the type with the field set.
+/// Fields not set by the builder get their `Default` values.
+pub fn make_typestate_builder(
+    struct_ident: &Ident,
+    struct_visibility: &Visibility,
+    struct_generics: &Generics,
+    field_types: &[(Member, Type)],
+    required_members: &[Member],
+    setter_configs: &SetterConfigs,
+) -> Result<TokenStream2> {
+    check_setter_configs(required_members, setter_configs)?;
+
+    let builder_ident =
+        format_ident!("{}{BUILDER_SUFFIX}", struct_ident.unraw());
+    let builder_fields =
+        make_builder_fields(struct_generics, field_types, required_members);
+    let states: Vec<&Ident> = builder_fields
+        .iter()
+        .filter_map(|builder_field| builder_field.state.as_ref())
+        .collect();
+
+    let struct_type_args = make_type_args(struct_generics);
+    let (impl_generics, struct_type_generics, where_clause) =
+        struct_generics.split_for_impl();
+    let mut state_generics = struct_generics.clone();
+    state_generics.params.extend(
+        states.iter().map(|state| {
+            GenericParam::Type(TypeParam::from((*state).clone()))
+        }),
+    );
+    let (state_impl_generics, _, _) = state_generics.split_for_impl();
+    // States default to `()`, so `<Struct>Builder` without them names
+    // the builder with no required fields set.
+    let mut declaration_generics = struct_generics.clone();
+    declaration_generics.params.extend(
+        states.iter().map(|state| {
+            GenericParam::Type(syn::parse_quote! { #state = () })
+        }),
+    );
+
+    let builder_type = |states: &[TokenStream2]| {
+        quote! { #builder_ident<#(#struct_type_args,)* #(#states),*> }
+    };
+    let unset_states = vec![quote! { () }; states.len()];
+    let set_states: Vec<TokenStream2> = builder_fields
+        .iter()
+        .filter(|builder_field| builder_field.state.is_some())
+        .map(|builder_field| {
+            let field_type = builder_field.field_type;
+            quote! { (#field_type,) }
+        })
+        .collect();
+    let generic_states: Vec<TokenStream2> =
+        states.iter().map(ToTokens::to_token_stream).collect();
+    let unset_builder_type = builder_type(&unset_states);
+    let set_builder_type = builder_type(&set_states);
+    let generic_builder_type = builder_type(&generic_states);
+
+    let builder_field_types = builder_fields.iter().map(|builder_field| {
+        match &builder_field.state {
+            Some(state) => quote! { #state },
+            None => builder_field.field_type.to_token_stream(),
+        }
+    });
+    let marker_type = quote! {
+        ::core::marker::PhantomData<fn() -> #struct_ident #struct_type_generics>
+    };
+    let default_predicates = builder_fields
+        .iter()
+        .filter(|builder_field| builder_field.state.is_none())
+        .map(|builder_field| {
+            let field_type = builder_field.field_type;
+            quote! { #field_type: ::core::default::Default }
+        });
+    let initial_values =
+        builder_fields
+            .iter()
+            .map(|builder_field| match builder_field.state {
+                Some(_) => quote! { () },
+                None => quote! { ::core::default::Default::default() },
+            });
+    let built_fields =
+        builder_fields
+            .iter()
+            .enumerate()
+            .map(|(index, builder_field)| {
+                let member = &builder_field.member;
+                let index = Index::from(index);
+                match builder_field.state {
+                    Some(_) => quote! { #member: self.#index.0 },
+                    None => quote! { #member: self.#index },
+                }
+            });
+
+    let builder_method = format_ident!("{BUILDER_METHOD}");
+    let build_method = format_ident!("{BUILD_METHOD}");
+    let state_names = states
+        .iter()
+        .map(|state| format!("`{state}`"))
+        .collect::<Vec<String>>()
+        .join(", ");
+    let builder_doc = if states.is_empty() {
+        format!(" Builder of [`{struct_ident}`].")
+    } else {
+        format!(
+            " Builder of [`{struct_ident}`]. Type parameters {state_names} \
+            track whether required fields are set: they are `()` until the \
+            field is set and `(T,)` afterwards."
+        )
+    };
+    let builder_method_doc = format!(
+        " Returns builder of `{struct_ident}` with no fields set. Fields not \
+        set by the builder get their `Default` values."
+    );
+    let build_method_doc = format!(
+        " Returns `{struct_ident}` with values of the builder. It exists \
+        only when all required fields are set."
+    );
+
+    let mut optional_setters: Vec<TokenStream2> = Vec::new();
+    let mut required_setters: Vec<TokenStream2> = Vec::new();
+
+    for (member, field, field_setter_configs) in setter_configs.iter() {
+        let index = builder_fields
+            .iter()
+            .position(|builder_field| builder_field.member == *member)
+            .expect("Every field has builder field.");
+        let builder_field = &builder_fields[index];
+        let field_index = Index::from(index);
+
+        for field_setter_config in field_setter_configs {
+            let SetterParts {
+                method_name,
+                method_visibility,
+                param,
+                param_type,
+                value,
+                docs,
+            } = make_setter_parts(member, field, field_setter_config);
+
+            let Some(state) = &builder_field.state else {
+                optional_setters.push(quote! {
+                    #docs
+                    #[must_use]
+                    #method_visibility fn #method_name(
+                        mut self,
+                        #param: #param_type,
+                    ) -> Self {
+                        self.#field_index = #value;
+                        self
+                    }
+                });
+                continue;
+            };
+
+            // The setter exists only while the field is not set, so
+            // required fields cannot be set twice.
+            let mut setter_generics = struct_generics.clone();
+            setter_generics.params.extend(
+                states.iter().filter(|other| **other != state).map(|other| {
+                    GenericParam::Type(TypeParam::from((*other).clone()))
+                }),
+            );
+            let (setter_impl_generics, _, _) =
+                setter_generics.split_for_impl();
+            let field_type = builder_field.field_type;
+            let state_args = |value: TokenStream2| -> Vec<TokenStream2> {
+                states
+                    .iter()
+                    .map(|other| {
+                        if *other == state {
+                            value.clone()
+                        } else {
+                            other.to_token_stream()
+                        }
+                    })
+                    .collect()
+            };
+            let from_type = builder_type(&state_args(quote! { () }));
+            let to_type = builder_type(&state_args(quote! { (#field_type,) }));
+            let values = (0..builder_fields.len()).map(|other_index| {
+                if other_index == index {
+                    quote! { (#value,) }
+                } else {
+                    let other_index = Index::from(other_index);
+                    quote! { self.#other_index }
+                }
+            });
+
+            required_setters.push(quote! {
+                impl #setter_impl_generics #from_type #where_clause {
+                    #docs
+                    #[must_use]
+                    #method_visibility fn #method_name(
+                        self,
+                        #param: #param_type,
+                    ) -> #to_type {
+                        #builder_ident(
+                            #(#values,)*
+                            ::core::marker::PhantomData,
+                        )
+                    }
+                }
+            });
+        }
+    }
+
+    Ok(quote! {
+        #[doc = #builder_doc]
+        #struct_visibility struct #builder_ident #declaration_generics(
+            #(#builder_field_types,)*
+            #marker_type,
+        )
+        #where_clause;
+
+        impl #impl_generics #struct_ident #struct_type_generics
+        #where_clause
+        {
+            #[doc = #builder_method_doc]
+            #[must_use]
+            #struct_visibility fn #builder_method() -> #unset_builder_type
+            where
+                #(#default_predicates,)*
+            {
+                #builder_ident(
+                    #(#initial_values,)*
+                    ::core::marker::PhantomData,
+                )
+            }
+        }
+
+        impl #state_impl_generics #generic_builder_type #where_clause {
+            #(#optional_setters)*
+        }
+
+        #(#required_setters)*
+
+        impl #impl_generics #set_builder_type #where_clause {
+            #[doc = #build_method_doc]
+            #[must_use]
+            #struct_visibility fn #build_method(
+                self,
+            ) -> #struct_ident #struct_type_generics {
+                #struct_ident {
+                    #(#built_fields,)*
+                }
+            }
+        }
+    })
+}
+
+/// Parts of setter of typestate builder, which are shared by setters
+/// of required and optional fields.
+struct SetterParts {
+    method_name: Ident,
+    method_visibility: Visibility,
+    param: Ident,
+    param_type: TokenStream2,
+    value: TokenStream2,
+    docs: TokenStream2,
+}
+
+fn make_setter_parts(
+    member: &Member,
+    field: &Field,
+    field_setter_config: &SetterConfig,
+) -> SetterParts {
+    let field_name = make_field_name(member);
+    // Setters of unnamed fields take `value`, since fields have no
+    // names to use for params.
+    let param = match member {
+        Member::Named(field_ident) => field_ident.clone(),
+        Member::Unnamed(_) => format_ident!("value"),
+    };
+    let method_name = format_ident!(
+        "{}",
+        field_setter_config.name(),
+        span = Span2::call_site(),
+    );
+    // Only the outer layer of nested options is stripped, so setters
+    // of `Option<Option<T>>` take `Option<T>`.
+    let option_inner_type = field_setter_config
+        .option_inner_type()
+        .filter(|_| field_setter_config.strip_option());
+    let value_type = option_inner_type.unwrap_or(&field.ty);
+    let (param_type, value) = match field_setter_config.with_into() {
+        true => (quote! { impl Into<#value_type> }, quote! { #param.into() }),
+        false => (quote! { #value_type }, quote! { #param }),
+    };
+    let value = match option_inner_type {
+        Some(_) => quote! { Some(#value) },
+        None => value,
+    };
+    let docs = make_docs(
+        format!("Returns the builder with the value of `{field_name}` set."),
+        field,
+        field_setter_config.doc(),
+    );
+
+    SetterParts {
+        method_name,
+        method_visibility: field_setter_config.visibility().clone(),
+        param,
+        param_type,
+        value,
+        docs,
+    }
+}
+
+/// Checks that every required field has a setter and setters use only
+/// options supported by typestate builders.
+fn check_setter_configs(
+    required_members: &[Member],
+    setter_configs: &SetterConfigs,
+) -> Result<()> {
+    let mut errors: Vec<Error> = Vec::new();
+
+    for (member, field, field_setter_configs) in setter_configs.iter() {
+        if required_members.contains(member) && field_setter_configs.is_empty()
+        {
+            errors.push(Error::new_spanned(
+                field,
+                "Required field has no setter, so the struct cannot be built.",
+            ));
+        }
+
+        let is_supported = |config: &SetterConfig| {
+            !(config.with_try_into()
+                || config.with_from_str()
+                || config.const_fn()
+                || config.receiver() != Receiver::Owned
+                || config.option_helpers()
+                || config.update()
+                || config.each().is_some()
+                || config.keyed_collection().is_some()
+                || config.type_change().is_some()
+                || config.validator().is_some()
+                || !config.checks().is_empty())
+        };
+        if !field_setter_configs.iter().all(is_supported) {
+            errors.push(Error::new_spanned(
+                field,
+                "Setters of typestate builders support only 'name', \
+                'visibility', 'prefix', 'suffix', 'with_into', \
+                'strip_option', 'option' and 'doc' options.",
+            ));
+        }
+    }
+    for member in required_members {
+        if !setter_configs.iter().any(|(other, ..)| other == member) {
+            let name = make_field_name(member);
+            errors.push(Error::new(
+                member_span(member),
+                format!(
+                    "Required field `{name}` has disabled setters, so the \
+                    struct cannot be built."
+                ),
+            ));
+        }
+    }
+
+    combine_errors(errors)
+}
+
+fn member_span(member: &Member) -> Span2 {
+    match member {
+        Member::Named(field_ident) => field_ident.span(),
+        Member::Unnamed(index) => index.span,
+    }
+}
+
+/// Makes fields of the builder in order of fields of the struct. State
+/// type parameters are named after required fields, e.g. `HostState`,
+/// and get numbers if the names are taken by the struct.
+fn make_builder_fields<'a>(
+    struct_generics: &Generics,
+    field_types: &'a [(Member, Type)],
+    required_members: &[Member],
+) -> Vec<BuilderField<'a>> {
+    let taken_tokens: TokenStream2 = field_types
+        .iter()
+        .map(|(_, field_type)| field_type.to_token_stream())
+        .chain([struct_generics.to_token_stream()])
+        .collect();
+    let mut states: Vec<Ident> = Vec::new();
+
+    field_types
+        .iter()
+        .map(|(member, field_type)| {
+            let state = required_members.contains(member).then(|| {
+                let name = make_pascal_case_name(member);
+                let state = (0..)
+                    .map(|index| match index {
+                        0 => format_ident!("{name}{STATE_SUFFIX}"),
+                        index => format_ident!("{name}{STATE_SUFFIX}{index}"),
+                    })
+                    .find(|state| {
+                        !contains_ident(taken_tokens.clone(), state)
+                            && !states.contains(state)
+                    })
+                    .expect("Some identifier is not taken.");
+                states.push(state.clone());
+                state
+            });
+            BuilderField {
+                member: member.clone(),
+                field_type,
+                state,
+            }
+        })
+        .collect()
+}
+
+/// Makes arguments of the struct type, e.g. `'a, T, N`.
+fn make_type_args(struct_generics: &Generics) -> Vec<TokenStream2> {
+    struct_generics
+        .params
+        .iter()
+        .map(|param| match param {
+            GenericParam::Lifetime(param) => param.lifetime.to_token_stream(),
+            GenericParam::Type(param) => param.ident.to_token_stream(),
+            GenericParam::Const(param) => param.ident.to_token_stream(),
+        })
+        .collect()
+}
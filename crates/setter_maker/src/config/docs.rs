@@ -0,0 +1,36 @@
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{
+    Attribute,
+    Field,
+};
+
+const DOC_ATTRIBUTE: &str = "doc";
+
+/// Makes doc attributes of method, which consist of generated lead
+/// line followed by docs of field, unless docs are set explicitly.
+pub fn make_docs(
+    lead: String,
+    field: &Field,
+    doc: Option<&str>,
+) -> TokenStream2 {
+    if let Some(doc) = doc {
+        return quote! { #[doc = #doc] };
+    }
+
+    let lead = format!(" {lead}");
+    let field_docs: Vec<&Attribute> = field
+        .attrs
+        .iter()
+        .filter(|attr| attr.path().is_ident(DOC_ATTRIBUTE))
+        .collect();
+
+    if field_docs.is_empty() {
+        return quote! { #[doc = #lead] };
+    }
+    quote! {
+        #[doc = #lead]
+        #[doc = ""]
+        #(#field_docs)*
+    }
+}
@@ -0,0 +1,351 @@
+use proc_macro2::TokenStream as TokenStream2;
+use quote::{
+    format_ident,
+    quote,
+};
+use syn::{
+    Error,
+    ExprRange,
+    Ident,
+    Member,
+    RangeLimits,
+    Result,
+    Type,
+    Visibility,
+    ext::IdentExt,
+};
+
+use super::{
+    setter_configs::{
+        SetterCheck,
+        SetterConfig,
+        SetterConfigs,
+        make_field_name,
+    },
+    setter_kind::SetterKind,
+};
+
+/// Variant of error enum with its declaration and arm of `Display`
+/// impl.
+struct ErrorVariant {
+    ident: Ident,
+    declaration: TokenStream2,
+    display_arm: TokenStream2,
+}
+
+pub fn make_error_enum_ident(struct_ident: &Ident, kind: SetterKind) -> Ident {
+    format_ident!("{}{}", struct_ident.unraw(), kind.error_enum_suffix())
+}
+
+/// Makes error enum of the struct, which has a variant for every
+/// declarative check of its setters. Nothing is made if setters have
+/// no checks.
+pub fn make_error_enum(
+    struct_ident: &Ident,
+    struct_visibility: &Visibility,
+    setter_configs: &SetterConfigs,
+    kind: SetterKind,
+) -> TokenStream2 {
+    let mut variants: Vec<ErrorVariant> = Vec::new();
+
+    for (member, field, field_setter_configs) in setter_configs.iter() {
+        // Setters of the same field share variants. Variants of
+        // different fields are checked not to clash by config.
+        let mut field_variants: Vec<ErrorVariant> = Vec::new();
+
+        for field_setter_config in field_setter_configs {
+            let value_type = field_setter_config.value_type(&field.ty);
+
+            for check in field_setter_config.checks() {
+                let variant = make_variant(member, value_type, check);
+                if !field_variants
+                    .iter()
+                    .any(|other| other.ident == variant.ident)
+                {
+                    field_variants.push(variant);
+                }
+            }
+        }
+
+        variants.extend(field_variants);
+    }
+
+    if variants.is_empty() {
+        return TokenStream2::new();
+    }
+
+    let enum_ident = make_error_enum_ident(struct_ident, kind);
+    let enum_doc = format!(
+        " Error of fallible {} setters of `{struct_ident}`.",
+        kind.name(),
+    );
+    let declarations = variants.iter().map(|variant| &variant.declaration);
+    let display_arms = variants.iter().map(|variant| &variant.display_arm);
+
+    quote! {
+        #[doc = #enum_doc]
+        #[derive(Debug, Clone, PartialEq)]
+        #struct_visibility enum #enum_ident {
+            #(#declarations),*
+        }
+
+        impl ::core::fmt::Display for #enum_ident {
+            fn fmt(
+                &self,
+                f: &mut ::core::fmt::Formatter<'_>,
+            ) -> ::core::fmt::Result {
+                match self {
+                    #(#display_arms)*
+                }
+            }
+        }
+
+        impl ::std::error::Error for #enum_ident {}
+    }
+}
+
+/// Checks that checks of different fields don't make variants with the
+/// same name, e.g. checks of `foo_bar` and `foo__bar` fields both make
+/// `FooBarEmpty`.
+pub fn check_variant_idents(setter_configs: &SetterConfigs) -> Result<()> {
+    let mut variant_members: Vec<(Ident, &Member)> = Vec::new();
+
+    for (member, _, field_setter_configs) in setter_configs {
+        let checks =
+            field_setter_configs.iter().flat_map(SetterConfig::checks);
+
+        for check in checks {
+            let ident = make_variant_ident(member, check);
+            let other_member = variant_members
+                .iter()
+                .find(|(other_ident, _)| *other_ident == ident)
+                .map(|(_, other_member)| *other_member);
+
+            match other_member {
+                Some(other_member) if other_member != member => {
+                    return Err(Error::new_spanned(
+                        member,
+                        format!(
+                            "Checks of `{}` field make error variant \
+                            `{ident}`, which checks of `{}` field already \
+                            make. Rename one of the fields.",
+                            make_field_name(member),
+                            make_field_name(other_member),
+                        ),
+                    ));
+                }
+                Some(_) => {}
+                None => variant_members.push((ident, member)),
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Makes statements of fallible setter, which return error, if value
+/// in `param` fails any declarative check of the setter.
+pub fn make_check_statements(
+    struct_ident: &Ident,
+    member: &Member,
+    field_setter_config: &SetterConfig,
+    param: &Ident,
+    kind: SetterKind,
+) -> TokenStream2 {
+    let enum_ident = make_error_enum_ident(struct_ident, kind);
+
+    field_setter_config
+        .checks()
+        .iter()
+        .map(|check| {
+            let variant_ident = make_variant_ident(member, check);
+
+            match check {
+                SetterCheck::Range(range) => quote! {
+                    if !(#range).contains(&#param) {
+                        return ::core::result::Result::Err(
+                            #enum_ident::#variant_ident {
+                                value: #param,
+                                range: #range,
+                            },
+                        );
+                    }
+                },
+                SetterCheck::NonEmpty => quote! {
+                    if #param.is_empty() {
+                        return ::core::result::Result::Err(
+                            #enum_ident::#variant_ident,
+                        );
+                    }
+                },
+                SetterCheck::MinLen(min_len) => quote! {
+                    if #param.len() < #min_len {
+                        return ::core::result::Result::Err(
+                            #enum_ident::#variant_ident {
+                                len: #param.len(),
+                                min_len: #min_len,
+                            },
+                        );
+                    }
+                },
+                SetterCheck::MaxLen(max_len) => quote! {
+                    if #param.len() > #max_len {
+                        return ::core::result::Result::Err(
+                            #enum_ident::#variant_ident {
+                                len: #param.len(),
+                                max_len: #max_len,
+                            },
+                        );
+                    }
+                },
+            }
+        })
+        .collect()
+}
+
+fn make_variant(
+    member: &Member,
+    value_type: &Type,
+    check: &SetterCheck,
+) -> ErrorVariant {
+    let ident = make_variant_ident(member, check);
+    let field_name = make_field_name(member);
+
+    let (declaration, display_arm) = match check {
+        SetterCheck::Range(range) => {
+            let doc = format!(" Value of `{field_name}` is out of range.");
+            let message = format!(
+                "Value {{:?}} of `{field_name}` is out of range {{:?}}."
+            );
+            let range_type = make_range_type(range, value_type);
+            (
+                quote! {
+                    #[doc = #doc]
+                    #ident {
+                        /// Rejected value.
+                        value: #value_type,
+                        /// Allowed range of values.
+                        range: #range_type,
+                    }
+                },
+                quote! {
+                    Self::#ident { value, range } => {
+                        ::core::write!(f, #message, value, range)
+                    }
+                },
+            )
+        }
+        SetterCheck::NonEmpty => {
+            let doc = format!(" Value of `{field_name}` is empty.");
+            let message = format!("`{field_name}` cannot be empty.");
+            (
+                quote! {
+                    #[doc = #doc]
+                    #ident
+                },
+                quote! {
+                    Self::#ident => ::core::write!(f, #message),
+                },
+            )
+        }
+        SetterCheck::MinLen(_) => {
+            let doc = format!(" Value of `{field_name}` is too short.");
+            let message =
+                format!("Length {{}} of `{field_name}` is less than {{}}.");
+            (
+                quote! {
+                    #[doc = #doc]
+                    #ident {
+                        /// Length of rejected value.
+                        len: usize,
+                        /// Minimal allowed length.
+                        min_len: usize,
+                    }
+                },
+                quote! {
+                    Self::#ident { len, min_len } => {
+                        ::core::write!(f, #message, len, min_len)
+                    }
+                },
+            )
+        }
+        SetterCheck::MaxLen(_) => {
+            let doc = format!(" Value of `{field_name}` is too long.");
+            let message =
+                format!("Length {{}} of `{field_name}` is greater than {{}}.");
+            (
+                quote! {
+                    #[doc = #doc]
+                    #ident {
+                        /// Length of rejected value.
+                        len: usize,
+                        /// Maximal allowed length.
+                        max_len: usize,
+                    }
+                },
+                quote! {
+                    Self::#ident { len, max_len } => {
+                        ::core::write!(f, #message, len, max_len)
+                    }
+                },
+            )
+        }
+    };
+
+    ErrorVariant {
+        ident,
+        declaration,
+        display_arm,
+    }
+}
+
+/// Makes name of variant from the field name in pascal case and the
+/// check, e.g. `PortOutOfRange`.
+fn make_variant_ident(member: &Member, check: &SetterCheck) -> Ident {
+    let field_name = make_pascal_case_name(member);
+    let check_name = match check {
+        SetterCheck::Range(_) => "OutOfRange",
+        SetterCheck::NonEmpty => "Empty",
+        SetterCheck::MinLen(_) => "TooShort",
+        SetterCheck::MaxLen(_) => "TooLong",
+    };
+
+    format_ident!("{field_name}{check_name}")
+}
+
+/// Makes name of field in pascal case, e.g. `MaxSize` or `Field0`.
+pub fn make_pascal_case_name(member: &Member) -> String {
+    match member {
+        Member::Named(field_ident) => field_ident
+            .unraw()
+            .to_string()
+            .split('_')
+            .filter(|word| !word.is_empty())
+            .map(|word| {
+                let mut chars = word.chars();
+                let first = chars.next().unwrap().to_ascii_uppercase();
+                first.to_string() + chars.as_str()
+            })
+            .collect(),
+        Member::Unnamed(index) => format!("Field{}", index.index),
+    }
+}
+
+fn make_range_type(range: &ExprRange, value_type: &Type) -> TokenStream2 {
+    match (&range.start, &range.end, &range.limits) {
+        (Some(_), Some(_), RangeLimits::HalfOpen(_)) => {
+            quote! { ::core::ops::Range<#value_type> }
+        }
+        (Some(_), Some(_), RangeLimits::Closed(_)) => {
+            quote! { ::core::ops::RangeInclusive<#value_type> }
+        }
+        (Some(_), None, _) => quote! { ::core::ops::RangeFrom<#value_type> },
+        (None, Some(_), RangeLimits::HalfOpen(_)) => {
+            quote! { ::core::ops::RangeTo<#value_type> }
+        }
+        (None, Some(_), RangeLimits::Closed(_)) => {
+            quote! { ::core::ops::RangeToInclusive<#value_type> }
+        }
+        (None, None, _) => quote! { ::core::ops::RangeFull },
+    }
+}
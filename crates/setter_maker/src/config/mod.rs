@@ -0,0 +1,5 @@
+pub mod docs;
+pub mod error_enum;
+pub mod setter_configs;
+pub mod setter_kind;
+pub mod struct_config;
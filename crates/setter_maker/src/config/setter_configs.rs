@@ -0,0 +1,1739 @@
+use proc_macro2::{
+    Span as Span2,
+    TokenStream as TokenStream2,
+    TokenTree,
+};
+use quote::ToTokens;
+use syn::{
+    Attribute,
+    Error,
+    Expr,
+    ExprLit,
+    ExprRange,
+    Field,
+    Fields,
+    GenericArgument,
+    GenericParam,
+    Generics,
+    Ident,
+    Index,
+    Lit,
+    LitBool,
+    LitInt,
+    LitStr,
+    Member,
+    Meta,
+    MetaNameValue,
+    Path,
+    PathArguments,
+    RangeLimits,
+    Result,
+    Token,
+    Type,
+    Visibility,
+    ext::IdentExt,
+    punctuated::Punctuated,
+};
+
+use super::{
+    error_enum::check_variant_idents,
+    setter_kind::SetterKind,
+    struct_config::{
+        Receiver,
+        StructConfig,
+        parse_receiver,
+    },
+};
+
+const NAME_PARAM: &str = "name";
+const PREFIX_PARAM: &str = "prefix";
+const SUFFIX_PARAM: &str = "suffix";
+const VISIBILITY_PARAM: &str = "visibility";
+const WITH_INTO_PARAM: &str = "with_into";
+const WITH_TRY_INTO_PARAM: &str = "with_try_into";
+const FROM_STR_PARAM: &str = "from_str";
+const CONST_FN_PARAM: &str = "const_fn";
+const RECEIVER_PARAM: &str = "receiver";
+const STRIP_OPTION_PARAM: &str = "strip_option";
+const OPTION_PARAM: &str = "option";
+const OPTION_HELPERS_PARAM: &str = "option_helpers";
+const REPLACE_PARAM: &str = "replace";
+const TAKE_PARAM: &str = "take";
+const SWAP_PARAM: &str = "swap";
+const UPDATE_PARAM: &str = "update";
+const CHANGE_TYPE_PARAM: &str = "change_type";
+const EACH_PARAM: &str = "each";
+const SEQUENCE_PARAM: &str = "sequence";
+const SINGULAR_PARAM: &str = "singular";
+const DOC_PARAM: &str = "doc";
+const VALIDATE_PARAM: &str = "validate";
+const ERROR_PARAM: &str = "error";
+const RANGE_PARAM: &str = "range";
+const NON_EMPTY_PARAM: &str = "non_empty";
+const MIN_LEN_PARAM: &str = "min_len";
+const MAX_LEN_PARAM: &str = "max_len";
+
+/// Paths of `Option` type, which setters recognise without `option`
+/// param. Other types named `Option` are not stripped.
+const OPTION_PATHS: [&[&str]; 3] = [
+    &["Option"],
+    &["std", "option", "Option"],
+    &["core", "option", "Option"],
+];
+
+/// Paths of sequence types, whose items setters can push with `each`
+/// param. Other types are recognised by `sequence` param.
+const SEQUENCE_PATHS: [&[&str]; 9] = [
+    &["Vec"],
+    &["std", "vec", "Vec"],
+    &["alloc", "vec", "Vec"],
+    &["VecDeque"],
+    &["std", "collections", "VecDeque"],
+    &["alloc", "collections", "VecDeque"],
+    &["LinkedList"],
+    &["std", "collections", "LinkedList"],
+    &["alloc", "collections", "LinkedList"],
+];
+
+/// Paths of map and set types, whose entries setters can insert and
+/// remove with `singular` param.
+const MAP_PATHS: [&[&str]; 5] = [
+    &["HashMap"],
+    &["std", "collections", "HashMap"],
+    &["BTreeMap"],
+    &["std", "collections", "BTreeMap"],
+    &["alloc", "collections", "BTreeMap"],
+];
+const SET_PATHS: [&[&str]; 5] = [
+    &["HashSet"],
+    &["std", "collections", "HashSet"],
+    &["BTreeSet"],
+    &["std", "collections", "BTreeSet"],
+    &["alloc", "collections", "BTreeSet"],
+];
+
+/// Paths of types with destructors, besides sequences, maps and sets.
+/// Const setters cannot drop previous values of such fields, so they
+/// are rejected. Other types with destructors are not recognised.
+const DROP_PATHS: [&[&str]; 12] = [
+    &["String"],
+    &["std", "string", "String"],
+    &["alloc", "string", "String"],
+    &["Box"],
+    &["std", "boxed", "Box"],
+    &["alloc", "boxed", "Box"],
+    &["Rc"],
+    &["std", "rc", "Rc"],
+    &["alloc", "rc", "Rc"],
+    &["Arc"],
+    &["std", "sync", "Arc"],
+    &["alloc", "sync", "Arc"],
+];
+
+/// Params supported only by one kind of setters. Methods of `replace`,
+/// `take` and `swap` return previous values, which doesn't fit builder
+/// setters, while only builder setters own the struct, so they can
+/// take it by value or change its type.
+const EXCLUSIVE_PARAMS: [(&str, SetterKind); 5] = [
+    (REPLACE_PARAM, SetterKind::Basic),
+    (TAKE_PARAM, SetterKind::Basic),
+    (SWAP_PARAM, SetterKind::Basic),
+    (RECEIVER_PARAM, SetterKind::Builder),
+    (CHANGE_TYPE_PARAM, SetterKind::Builder),
+];
+
+const TRY_PREFIX: &str = "try";
+
+/// Configs of fields in declaration order. Configs of a single field
+/// are kept in order of its attributes, so generated methods are
+/// always emitted in the same order. Fields are paired with members,
+/// which access them, since fields of tuple structs have no names.
+pub type SetterConfigs<'a> = Vec<(Member, &'a Field, Vec<SetterConfig>)>;
+
+#[derive(Debug)]
+pub struct SetterConfig {
+    name: String,
+    visibility: Visibility,
+    with_into: bool,
+    with_try_into: bool,
+    from_str: bool,
+    const_fn: bool,
+    receiver: Receiver,
+    option_inner_type: Option<Type>,
+    strip_option: bool,
+    option_helpers: bool,
+    replace: bool,
+    take: bool,
+    swap: bool,
+    update: bool,
+    each: Option<String>,
+    sequence_item_type: Option<Type>,
+    singular: Option<String>,
+    keyed_collection: Option<KeyedCollection>,
+    type_change: Option<TypeChange>,
+    suffix: String,
+    validator: Option<Path>,
+    error_type: Option<Type>,
+    checks: Vec<SetterCheck>,
+    doc: Option<String>,
+}
+
+/// Declarative check of values, which fallible setter makes before
+/// setting them. Failed checks are reported by variants of error
+/// enum generated for the struct.
+#[derive(Debug, Clone)]
+pub enum SetterCheck {
+    Range(ExprRange),
+    NonEmpty,
+    MinLen(usize),
+    MaxLen(usize),
+}
+
+/// Map or set field with types of its entries, whose helpers are
+/// named after `singular` param.
+#[derive(Debug)]
+pub enum KeyedCollection {
+    Map {
+        key_type: Type,
+        value_type: Box<Type>,
+    },
+    Set {
+        item_type: Type,
+    },
+}
+
+/// Change of type parameter of the struct, which type-changing setter
+/// makes. Other fields are moved into the struct of the new type.
+#[derive(Debug)]
+pub struct TypeChange {
+    type_param: Ident,
+    other_members: Vec<Member>,
+}
+
+impl TypeChange {
+    /// Returns type parameter, which is the type of the field.
+    pub fn type_param(&self) -> &Ident {
+        &self.type_param
+    }
+
+    pub fn other_members(&self) -> &[Member] {
+        &self.other_members
+    }
+}
+
+/// Generics and all fields of the struct including disabled ones,
+/// which configs of type-changing setters are checked against.
+struct StructShape<'a> {
+    generics: &'a Generics,
+    fields: Vec<(Member, Type)>,
+}
+
+/// Raw values of config attribute params. Literals are kept as is,
+/// so errors can point at them.
+#[derive(Debug, Default)]
+struct RawSetterParams {
+    name: Option<LitStr>,
+    prefix: Option<LitStr>,
+    suffix: Option<LitStr>,
+    visibility: Option<LitStr>,
+    with_into: Option<LitBool>,
+    with_try_into: Option<LitBool>,
+    from_str: Option<LitBool>,
+    const_fn: Option<LitBool>,
+    receiver: Option<LitStr>,
+    strip_option: Option<LitBool>,
+    option: Option<LitBool>,
+    option_helpers: Option<LitBool>,
+    replace: Option<LitBool>,
+    take: Option<LitBool>,
+    swap: Option<LitBool>,
+    update: Option<LitBool>,
+    change_type: Option<LitBool>,
+    each: Option<Lit>,
+    sequence: Option<LitBool>,
+    singular: Option<LitStr>,
+    validate: Option<LitStr>,
+    error: Option<LitStr>,
+    range: Option<LitStr>,
+    non_empty: Option<LitBool>,
+    min_len: Option<LitInt>,
+    max_len: Option<LitInt>,
+    doc: Option<LitStr>,
+}
+
+impl RawSetterParams {
+    /// Returns spans of params of declarative checks, which are set.
+    fn check_spans(&self) -> Vec<Span2> {
+        let mut spans: Vec<Span2> = Vec::new();
+        spans.extend(self.range.as_ref().map(LitStr::span));
+        spans.extend(self.non_empty.as_ref().map(LitBool::span));
+        spans.extend(self.min_len.as_ref().map(LitInt::span));
+        spans.extend(self.max_len.as_ref().map(LitInt::span));
+        spans
+    }
+}
+
+impl SetterConfig {
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn visibility(&self) -> &Visibility {
+        &self.visibility
+    }
+
+    pub fn with_into(&self) -> bool {
+        self.with_into
+    }
+
+    /// Returns whether setter is fallible and takes values, which are
+    /// converted by `TryInto`.
+    pub fn with_try_into(&self) -> bool {
+        self.with_try_into
+    }
+
+    /// Returns whether setter is accompanied by a method, which parses
+    /// values from strings by `FromStr`.
+    pub fn with_from_str(&self) -> bool {
+        self.from_str
+    }
+
+    pub fn const_fn(&self) -> bool {
+        self.const_fn
+    }
+
+    pub fn receiver(&self) -> Receiver {
+        self.receiver
+    }
+
+    /// Returns inner type of field, if the field is an `Option`.
+    pub fn option_inner_type(&self) -> Option<&Type> {
+        self.option_inner_type.as_ref()
+    }
+
+    pub fn strip_option(&self) -> bool {
+        self.strip_option
+    }
+
+    pub fn option_helpers(&self) -> bool {
+        self.option_helpers
+    }
+
+    pub fn replace(&self) -> bool {
+        self.replace
+    }
+
+    pub fn take(&self) -> bool {
+        self.take
+    }
+
+    pub fn swap(&self) -> bool {
+        self.swap
+    }
+
+    /// Returns whether setter is accompanied by a method, which
+    /// changes the value of field by a closure.
+    pub fn update(&self) -> bool {
+        self.update
+    }
+
+    /// Returns name of method, which pushes a single item into the
+    /// sequence field. Setter takes iterators of items, if it's set.
+    pub fn each(&self) -> Option<&str> {
+        self.each.as_deref()
+    }
+
+    /// Returns type of items of sequence field, if `each` is set.
+    pub fn sequence_item_type(&self) -> Option<&Type> {
+        self.sequence_item_type.as_ref()
+    }
+
+    /// Returns singular name of entries of map or set field, which
+    /// names of its helpers are made of.
+    pub fn singular(&self) -> Option<&str> {
+        self.singular.as_deref()
+    }
+
+    pub fn keyed_collection(&self) -> Option<&KeyedCollection> {
+        self.keyed_collection.as_ref()
+    }
+
+    /// Returns change of type parameter, if the setter changes type
+    /// of the struct.
+    pub fn type_change(&self) -> Option<&TypeChange> {
+        self.type_change.as_ref()
+    }
+
+    /// Returns suffix of the setter, which names of helper methods
+    /// are made of. It's the field name, unless `suffix` is set.
+    pub fn suffix(&self) -> &str {
+        &self.suffix
+    }
+
+    /// Returns path of function, which validates values before they
+    /// are set. Setter is fallible, if it's set.
+    pub fn validator(&self) -> Option<&Path> {
+        self.validator.as_ref()
+    }
+
+    pub fn error_type(&self) -> Option<&Type> {
+        self.error_type.as_ref()
+    }
+
+    pub fn checks(&self) -> &[SetterCheck] {
+        &self.checks
+    }
+
+    /// Returns type of values, which setter sets. It's inner type of
+    /// `Option` field, if the option is stripped.
+    pub fn value_type<'a>(&'a self, field_type: &'a Type) -> &'a Type {
+        self.option_inner_type
+            .as_ref()
+            .filter(|_| self.strip_option)
+            .unwrap_or(field_type)
+    }
+
+    pub fn doc(&self) -> Option<&str> {
+        self.doc.as_deref()
+    }
+}
+
+/// Extracts configs of every field and removes config attributes
+/// from fields. Errors of all fields are combined, so they are
+/// reported together.
+pub fn make_setter_configs<'a>(
+    fields: &'a mut Fields,
+    generics: &Generics,
+    struct_config: &StructConfig,
+) -> Result<SetterConfigs<'a>> {
+    let fields = match fields {
+        Fields::Named(fields) => &mut fields.named,
+        Fields::Unnamed(fields) => &mut fields.unnamed,
+        Fields::Unit => return Ok(SetterConfigs::new()),
+    };
+    let struct_shape = StructShape {
+        generics,
+        fields: fields
+            .iter()
+            .enumerate()
+            .map(|(index, field)| {
+                (make_member(field, index), field.ty.clone())
+            })
+            .collect(),
+    };
+    let kind = struct_config.kind();
+    let mut setter_configs: SetterConfigs = SetterConfigs::new();
+    let mut errors: Vec<Error> = Vec::new();
+
+    for (index, field) in fields.iter_mut().enumerate() {
+        let member = make_member(field, index);
+        let is_disabled = field
+            .attrs
+            .iter()
+            .any(|attr| attr.path().is_ident(kind.disable_attribute()));
+
+        if is_disabled {
+            remove_attributes(field, kind);
+            continue;
+        }
+
+        let field_setter_configs =
+            extract_configs(field, &member, struct_config, &struct_shape);
+        remove_attributes(field, kind);
+
+        match field_setter_configs {
+            Ok(field_setter_configs) => {
+                setter_configs.push((member, field, field_setter_configs))
+            }
+            Err(error) => errors.push(error),
+        }
+    }
+
+    combine_errors(errors)?;
+    check_variant_idents(&setter_configs)?;
+    Ok(setter_configs)
+}
+
+/// Extracts configs from config attributes of field and returns
+/// them. If named field has no attribute, this function returns Vec
+/// with a default config. Unnamed fields get no setters by default,
+/// so setters like `set_0` are made only by explicit attributes.
+fn extract_configs(
+    field: &Field,
+    member: &Member,
+    struct_config: &StructConfig,
+    struct_shape: &StructShape,
+) -> Result<Vec<SetterConfig>> {
+    let attributes: Vec<&Attribute> = field
+        .attrs
+        .iter()
+        .filter(|attr| {
+            attr.path()
+                .is_ident(struct_config.kind().config_attribute())
+        })
+        .collect();
+
+    if attributes.is_empty() {
+        if let Member::Unnamed(_) = member {
+            return Ok(Vec::new());
+        }
+
+        let params = RawSetterParams::default();
+        let name = make_name(&params, member, struct_config)?;
+        let visibility = make_default_visibility(struct_config);
+        let const_fn = struct_config.const_fn();
+        check_const_fn(&field.ty, const_fn, None)?;
+        let with_into = make_default_with_into(const_fn, struct_config);
+
+        return Ok(vec![SetterConfig {
+            name,
+            visibility,
+            with_into,
+            with_try_into: false,
+            from_str: false,
+            const_fn,
+            receiver: struct_config.receiver(),
+            option_inner_type: make_option_inner_type(&field.ty, None)?,
+            strip_option: struct_config.strip_option(),
+            option_helpers: false,
+            replace: false,
+            take: false,
+            swap: false,
+            update: false,
+            each: None,
+            sequence_item_type: None,
+            singular: None,
+            keyed_collection: None,
+            type_change: None,
+            suffix: make_field_name(member),
+            validator: None,
+            error_type: None,
+            checks: Vec::new(),
+            doc: None,
+        }]);
+    }
+
+    let mut setter_configs: Vec<SetterConfig> = Vec::new();
+    let mut method_names: Vec<String> = Vec::new();
+    let mut errors: Vec<Error> = Vec::new();
+
+    for attribute in attributes {
+        let setter_config = extract_config(
+            &field.ty,
+            member,
+            attribute,
+            struct_config,
+            struct_shape,
+        )
+        .and_then(|setter_config| {
+            let config_method_names =
+                make_method_names(&setter_config, struct_config.kind());
+            check_method_names(
+                attribute,
+                &config_method_names,
+                &method_names,
+            )?;
+            method_names.extend(config_method_names);
+            Ok(setter_config)
+        });
+
+        match setter_config {
+            Ok(setter_config) => setter_configs.push(setter_config),
+            Err(error) => errors.push(error),
+        }
+    }
+
+    combine_errors(errors)?;
+    check_ranges(&field.ty, &setter_configs, struct_shape.generics)?;
+    Ok(setter_configs)
+}
+
+/// Returns names of setter and helper methods, which config makes.
+/// They mirror names made by `setter_methods` and `helper_methods`
+/// modules of the kind.
+fn make_method_names(
+    setter_config: &SetterConfig,
+    kind: SetterKind,
+) -> Vec<String> {
+    let name = setter_config.name();
+    let suffix = setter_config.suffix();
+    let mut method_names: Vec<String> = vec![name.to_owned()];
+
+    if setter_config.from_str {
+        method_names.push(format!("{name}_str"));
+    }
+    if setter_config.option_helpers
+        && setter_config.option_inner_type.is_some()
+    {
+        match kind {
+            SetterKind::Basic => method_names.extend([
+                format!("clear_{suffix}"),
+                format!("take_{suffix}"),
+                format!("{suffix}_get_or_insert_with"),
+            ]),
+            SetterKind::Builder => {
+                method_names.push(format!("without_{suffix}"))
+            }
+        }
+    }
+    if setter_config.update {
+        match kind {
+            SetterKind::Basic => method_names.push(format!("update_{suffix}")),
+            SetterKind::Builder => method_names.push(format!("map_{suffix}")),
+        }
+    }
+    if setter_config.replace {
+        method_names.push(format!("replace_{suffix}"));
+    }
+    if setter_config.take {
+        method_names.push(format!("take_{suffix}"));
+    }
+    if setter_config.swap {
+        method_names.push(format!("swap_{suffix}"));
+    }
+    if let Some(each) = setter_config.each() {
+        method_names.push(each.to_owned());
+        match kind {
+            SetterKind::Basic => method_names.push(format!("extend_{suffix}")),
+            SetterKind::Builder => {
+                method_names.push(format!("with_{suffix}_items"))
+            }
+        }
+    }
+    if let (Some(singular), Some(keyed_collection)) =
+        (setter_config.singular(), setter_config.keyed_collection())
+    {
+        match (kind, keyed_collection) {
+            (SetterKind::Basic, KeyedCollection::Map { .. }) => method_names
+                .extend([
+                    format!("insert_{singular}"),
+                    format!("remove_{singular}"),
+                    format!("contains_{singular}"),
+                ]),
+            (SetterKind::Basic, KeyedCollection::Set { .. }) => method_names
+                .extend([
+                    format!("add_{singular}"),
+                    format!("remove_{singular}"),
+                    format!("contains_{singular}"),
+                ]),
+            (SetterKind::Builder, _) => {
+                method_names.push(format!("with_{singular}"))
+            }
+        }
+    }
+
+    method_names
+}
+
+/// Checks that methods of config attribute are not made by previous
+/// attributes of the field, since helpers are named after the field
+/// and don't differ between attributes, unless they are renamed.
+fn check_method_names(
+    attribute: &Attribute,
+    method_names: &[String],
+    previous_method_names: &[String],
+) -> Result<()> {
+    match method_names
+        .iter()
+        .find(|method_name| previous_method_names.contains(method_name))
+    {
+        Some(method_name) => Err(Error::new_spanned(
+            attribute,
+            format!(
+                "Method `{method_name}` is already made by another \
+                attribute of the field. Set '{SUFFIX_PARAM}', \
+                '{EACH_PARAM}' or '{SINGULAR_PARAM}' param to rename \
+                helpers, or '{NAME_PARAM}' param to rename the setter."
+            ),
+        )),
+        None => Ok(()),
+    }
+}
+
+/// Checks that `range` checks of setters of the same field use the
+/// same kind of range and type of values, since they share a variant
+/// of the error enum, which holds both of them. The enum has no
+/// generics, so the type of values cannot use generics of the struct.
+fn check_ranges(
+    field_type: &Type,
+    field_setter_configs: &[SetterConfig],
+    generics: &Generics,
+) -> Result<()> {
+    let mut first_range: Option<(&ExprRange, &Type)> = None;
+
+    for field_setter_config in field_setter_configs {
+        let value_type = field_setter_config.value_type(field_type);
+
+        for check in &field_setter_config.checks {
+            let SetterCheck::Range(range) = check else {
+                continue;
+            };
+            if let Some(generic_param) =
+                find_generic_param(value_type, generics)
+            {
+                return Err(Error::new_spanned(
+                    range,
+                    format!(
+                        "'{RANGE_PARAM}' param cannot be set for values of \
+                        type using generic parameter `{generic_param}` of \
+                        the struct, since error enum has no generics."
+                    ),
+                ));
+            }
+            let Some((first_range, first_value_type)) = first_range else {
+                first_range = Some((range, value_type));
+                continue;
+            };
+
+            if range_kind(range) != range_kind(first_range)
+                || value_type != first_value_type
+            {
+                return Err(Error::new_spanned(
+                    range,
+                    format!(
+                        "'{RANGE_PARAM}' params of setters of the same field \
+                        must use the same kind of range and type of values, \
+                        since their errors share a variant."
+                    ),
+                ));
+            }
+        }
+    }
+
+    Ok(())
+}
+
+/// Finds generic parameter of the struct, which type uses, and
+/// returns its name.
+fn find_generic_param(
+    value_type: &Type,
+    generics: &Generics,
+) -> Option<String> {
+    generics.params.iter().find_map(|param| {
+        let (ident, name) = match param {
+            GenericParam::Type(type_param) => {
+                (&type_param.ident, type_param.ident.to_string())
+            }
+            GenericParam::Lifetime(lifetime_param) => (
+                &lifetime_param.lifetime.ident,
+                lifetime_param.lifetime.to_string(),
+            ),
+            GenericParam::Const(const_param) => {
+                (&const_param.ident, const_param.ident.to_string())
+            }
+        };
+        contains_ident(value_type.to_token_stream(), ident).then_some(name)
+    })
+}
+
+/// Returns which bounds range has and whether the end is inclusive.
+fn range_kind(range: &ExprRange) -> (bool, bool, bool) {
+    (
+        range.start.is_some(),
+        range.end.is_some(),
+        matches!(range.limits, RangeLimits::Closed(_)),
+    )
+}
+
+fn extract_config(
+    field_type: &Type,
+    member: &Member,
+    attribute: &Attribute,
+    struct_config: &StructConfig,
+    struct_shape: &StructShape,
+) -> Result<SetterConfig> {
+    let name_values: Punctuated<MetaNameValue, Token![,]> =
+        match &attribute.meta {
+            Meta::Path(_) => Punctuated::new(),
+            _ => attribute.parse_args_with(Punctuated::parse_terminated)?,
+        };
+
+    let mut params = RawSetterParams::default();
+    let mut errors: Vec<Error> = Vec::new();
+
+    for name_value in name_values {
+        if let Err(error) = parse_attribute_param(
+            name_value,
+            &mut params,
+            struct_config.kind(),
+        ) {
+            errors.push(error);
+        }
+    }
+    combine_errors(errors)?;
+
+    let name = make_name(&params, member, struct_config);
+    let visibility = match params.visibility.as_ref() {
+        Some(visibility) => visibility.parse(),
+        None => Ok(make_default_visibility(struct_config)),
+    };
+    let const_fn = params
+        .const_fn
+        .as_ref()
+        .map_or(struct_config.const_fn(), LitBool::value);
+    let const_fn_check =
+        check_const_fn(field_type, const_fn, params.const_fn.as_ref());
+    let with_into = match (const_fn, params.with_into.as_ref()) {
+        (true, Some(with_into)) if with_into.value() => Err(Error::new(
+            with_into.span(),
+            format!(
+                "'{WITH_INTO_PARAM}' param cannot be set to true for \
+                const setters, because `Into::into` cannot be called \
+                in const fn."
+            ),
+        )),
+        (_, Some(with_into)) => Ok(with_into.value()),
+        (const_fn, None) => {
+            Ok(make_default_with_into(const_fn, struct_config))
+        }
+    };
+    let receiver = params
+        .receiver
+        .as_ref()
+        .map_or(Ok(struct_config.receiver()), parse_receiver);
+    let with_try_into = make_conversion(
+        params.with_try_into.as_ref(),
+        WITH_TRY_INTO_PARAM,
+        &params,
+        const_fn,
+    )
+    .and_then(|with_try_into| match params.with_into.as_ref() {
+        Some(with_into) if with_try_into && with_into.value() => {
+            Err(Error::new(
+                with_into.span(),
+                format!(
+                    "'{WITH_INTO_PARAM}' param cannot be set to true with \
+                    '{WITH_TRY_INTO_PARAM}' param."
+                ),
+            ))
+        }
+        _ => Ok(with_try_into),
+    });
+    let from_str = make_conversion(
+        params.from_str.as_ref(),
+        FROM_STR_PARAM,
+        &params,
+        const_fn,
+    );
+    let strip_option = params
+        .strip_option
+        .as_ref()
+        .map_or(struct_config.strip_option(), LitBool::value);
+    let option_inner_type =
+        make_option_inner_type(field_type, params.option.as_ref());
+    let option_helpers =
+        match (params.option_helpers.as_ref(), &option_inner_type) {
+            (Some(option_helpers), Ok(None)) if option_helpers.value() => {
+                Err(Error::new(
+                    option_helpers.span(),
+                    format!(
+                        "'{OPTION_HELPERS_PARAM}' param can be set to true \
+                    only for Option fields."
+                    ),
+                ))
+            }
+            (option_helpers, _) => {
+                Ok(option_helpers.is_some_and(LitBool::value))
+            }
+        };
+    let replace =
+        make_mem_helper(params.replace.as_ref(), REPLACE_PARAM, const_fn);
+    let take = make_mem_helper(params.take.as_ref(), TAKE_PARAM, const_fn)
+        .and_then(|take| match params.option_helpers.as_ref() {
+            Some(option_helpers) if take && option_helpers.value() => {
+                Err(Error::new(
+                    option_helpers.span(),
+                    format!(
+                        "'{OPTION_HELPERS_PARAM}' param cannot be set to \
+                        true with '{TAKE_PARAM}' param, since both make \
+                        the same method."
+                    ),
+                ))
+            }
+            _ => Ok(take),
+        });
+    let swap = make_mem_helper(params.swap.as_ref(), SWAP_PARAM, const_fn);
+    let update = match params.update.as_ref() {
+        Some(update) if update.value() && const_fn => Err(Error::new(
+            update.span(),
+            format!(
+                "'{UPDATE_PARAM}' param cannot be set to true for const \
+                setters, because closures cannot be called in const fn."
+            ),
+        )),
+        update => Ok(update.is_some_and(LitBool::value)),
+    };
+    let suffix = params
+        .suffix
+        .as_ref()
+        .map_or_else(|| make_field_name(member), LitStr::value);
+    let each = make_each(
+        field_type,
+        &params,
+        &suffix,
+        struct_config.kind(),
+        const_fn,
+        with_try_into
+            .as_ref()
+            .is_ok_and(|with_try_into| *with_try_into),
+    );
+    let keyed_collection =
+        make_keyed_collection(field_type, params.singular.as_ref(), const_fn);
+    let type_change = make_type_change(
+        field_type,
+        member,
+        &params,
+        const_fn,
+        receiver.as_ref().ok().copied(),
+        struct_shape,
+    );
+    let validator = match params.validate.as_ref() {
+        Some(validate) if const_fn => Err(Error::new(
+            validate.span(),
+            format!(
+                "'{VALIDATE_PARAM}' param cannot be set for const \
+                setters, because validators cannot be called in const fn."
+            ),
+        )),
+        validate => validate.map(LitStr::parse).transpose(),
+    };
+    let checks = make_checks(&params, const_fn);
+    let error_type = match (params.error.as_ref(), params.validate.as_ref()) {
+        (Some(error), None) => Err(Error::new(
+            error.span(),
+            format!(
+                "'{ERROR_PARAM}' param can be set only with \
+                '{VALIDATE_PARAM}' param."
+            ),
+        )),
+        (None, Some(validate)) => Err(Error::new(
+            validate.span(),
+            format!(
+                "'{ERROR_PARAM}' param must be set with '{VALIDATE_PARAM}' \
+                param, since error type of the validator cannot be \
+                inferred."
+            ),
+        )),
+        (error, _) => error.map(LitStr::parse).transpose(),
+    };
+
+    let errors = [
+        name.as_ref().err(),
+        visibility.as_ref().err(),
+        const_fn_check.as_ref().err(),
+        with_into.as_ref().err(),
+        receiver.as_ref().err(),
+        with_try_into.as_ref().err(),
+        from_str.as_ref().err(),
+        option_inner_type.as_ref().err(),
+        option_helpers.as_ref().err(),
+        replace.as_ref().err(),
+        take.as_ref().err(),
+        swap.as_ref().err(),
+        update.as_ref().err(),
+        each.as_ref().err(),
+        keyed_collection.as_ref().err(),
+        type_change.as_ref().err(),
+        validator.as_ref().err(),
+        error_type.as_ref().err(),
+        checks.as_ref().err(),
+    ];
+    combine_errors(errors.into_iter().flatten().cloned().collect())?;
+
+    let with_try_into = with_try_into?;
+    let (each, sequence_item_type) = each?.unzip();
+    let (singular, keyed_collection) = keyed_collection?.unzip();
+
+    Ok(SetterConfig {
+        name: name?,
+        visibility: visibility?,
+        // Values converted by `TryInto` are not converted again.
+        with_into: with_into? && !with_try_into,
+        with_try_into,
+        from_str: from_str?,
+        const_fn,
+        receiver: receiver?,
+        option_inner_type: option_inner_type?,
+        strip_option,
+        option_helpers: option_helpers?,
+        replace: replace?,
+        take: take?,
+        swap: swap?,
+        update: update?,
+        each,
+        sequence_item_type,
+        singular,
+        keyed_collection,
+        type_change: type_change?,
+        suffix,
+        validator: validator?,
+        error_type: error_type?,
+        checks: checks?,
+        doc: params.doc.as_ref().map(LitStr::value),
+    })
+}
+
+fn parse_attribute_param(
+    name_value: MetaNameValue,
+    params: &mut RawSetterParams,
+    kind: SetterKind,
+) -> Result<()> {
+    let Some(param_ident) = name_value.path.get_ident() else {
+        return Err(Error::new_spanned(&name_value.path, "Unexpected param."));
+    };
+    kind.check_param(param_ident, &EXCLUSIVE_PARAMS)?;
+    let Expr::Lit(ExprLit { lit, .. }) = name_value.value else {
+        return Err(Error::new_spanned(
+            &name_value.value,
+            "Expected literal value.",
+        ));
+    };
+
+    match (param_ident.to_string().as_str(), lit) {
+        (NAME_PARAM, Lit::Str(value)) => {
+            set_param(&mut params.name, value, param_ident)
+        }
+        (PREFIX_PARAM, Lit::Str(value)) => {
+            set_param(&mut params.prefix, value, param_ident)
+        }
+        (SUFFIX_PARAM, Lit::Str(value)) => {
+            set_param(&mut params.suffix, value, param_ident)
+        }
+        (VISIBILITY_PARAM, Lit::Str(value)) => {
+            set_param(&mut params.visibility, value, param_ident)
+        }
+        (RECEIVER_PARAM, Lit::Str(value)) => {
+            set_param(&mut params.receiver, value, param_ident)
+        }
+        (DOC_PARAM, Lit::Str(value)) => {
+            set_param(&mut params.doc, value, param_ident)
+        }
+        (VALIDATE_PARAM, Lit::Str(value)) => {
+            set_param(&mut params.validate, value, param_ident)
+        }
+        (ERROR_PARAM, Lit::Str(value)) => {
+            set_param(&mut params.error, value, param_ident)
+        }
+        (RANGE_PARAM, Lit::Str(value)) => {
+            set_param(&mut params.range, value, param_ident)
+        }
+        (NON_EMPTY_PARAM, Lit::Bool(value)) => {
+            set_param(&mut params.non_empty, value, param_ident)
+        }
+        (MIN_LEN_PARAM, Lit::Int(value)) => {
+            set_param(&mut params.min_len, value, param_ident)
+        }
+        (MAX_LEN_PARAM, Lit::Int(value)) => {
+            set_param(&mut params.max_len, value, param_ident)
+        }
+        (WITH_INTO_PARAM, Lit::Bool(value)) => {
+            set_param(&mut params.with_into, value, param_ident)
+        }
+        (WITH_TRY_INTO_PARAM, Lit::Bool(value)) => {
+            set_param(&mut params.with_try_into, value, param_ident)
+        }
+        (FROM_STR_PARAM, Lit::Bool(value)) => {
+            set_param(&mut params.from_str, value, param_ident)
+        }
+        (CONST_FN_PARAM, Lit::Bool(value)) => {
+            set_param(&mut params.const_fn, value, param_ident)
+        }
+        (STRIP_OPTION_PARAM, Lit::Bool(value)) => {
+            set_param(&mut params.strip_option, value, param_ident)
+        }
+        (OPTION_PARAM, Lit::Bool(value)) => {
+            set_param(&mut params.option, value, param_ident)
+        }
+        (OPTION_HELPERS_PARAM, Lit::Bool(value)) => {
+            set_param(&mut params.option_helpers, value, param_ident)
+        }
+        (REPLACE_PARAM, Lit::Bool(value)) => {
+            set_param(&mut params.replace, value, param_ident)
+        }
+        (TAKE_PARAM, Lit::Bool(value)) => {
+            set_param(&mut params.take, value, param_ident)
+        }
+        (SWAP_PARAM, Lit::Bool(value)) => {
+            set_param(&mut params.swap, value, param_ident)
+        }
+        (UPDATE_PARAM, Lit::Bool(value)) => {
+            set_param(&mut params.update, value, param_ident)
+        }
+        (CHANGE_TYPE_PARAM, Lit::Bool(value)) => {
+            set_param(&mut params.change_type, value, param_ident)
+        }
+        (EACH_PARAM, value @ (Lit::Str(_) | Lit::Bool(_))) => {
+            set_param(&mut params.each, value, param_ident)
+        }
+        (SEQUENCE_PARAM, Lit::Bool(value)) => {
+            set_param(&mut params.sequence, value, param_ident)
+        }
+        (SINGULAR_PARAM, Lit::Str(value)) => {
+            set_param(&mut params.singular, value, param_ident)
+        }
+        (
+            NAME_PARAM | PREFIX_PARAM | SUFFIX_PARAM | VISIBILITY_PARAM
+            | DOC_PARAM | VALIDATE_PARAM | ERROR_PARAM | RANGE_PARAM
+            | SINGULAR_PARAM | RECEIVER_PARAM,
+            lit,
+        ) => Err(Error::new_spanned(lit, "Expected string value.")),
+        (
+            WITH_INTO_PARAM | WITH_TRY_INTO_PARAM | FROM_STR_PARAM
+            | CONST_FN_PARAM | STRIP_OPTION_PARAM | OPTION_PARAM
+            | OPTION_HELPERS_PARAM | REPLACE_PARAM | TAKE_PARAM | SWAP_PARAM
+            | UPDATE_PARAM | CHANGE_TYPE_PARAM | SEQUENCE_PARAM
+            | NON_EMPTY_PARAM,
+            lit,
+        ) => Err(Error::new_spanned(lit, "Expected bool value.")),
+        (EACH_PARAM, lit) => {
+            Err(Error::new_spanned(lit, "Expected string or bool value."))
+        }
+        (MIN_LEN_PARAM | MAX_LEN_PARAM, lit) => {
+            Err(Error::new_spanned(lit, "Expected integer value."))
+        }
+        _ => Err(Error::new_spanned(
+            param_ident,
+            format!("Unexpected param '{param_ident}'."),
+        )),
+    }
+}
+
+fn set_param<T>(
+    param: &mut Option<T>,
+    value: T,
+    param_ident: &Ident,
+) -> Result<()> {
+    if param.is_some() {
+        return Err(Error::new_spanned(
+            param_ident,
+            format!("Duplicate '{param_ident}' param."),
+        ));
+    }
+    *param = Some(value);
+    Ok(())
+}
+
+/// Makes name of setter. Prefix of the struct config is used, when
+/// the setter doesn't set its own one. Names of fallible setters
+/// start with `try_`, unless they are set explicitly.
+fn make_name(
+    params: &RawSetterParams,
+    member: &Member,
+    struct_config: &StructConfig,
+) -> Result<String> {
+    let field_name = make_field_name(member);
+    let default_prefix = struct_config.prefix().map_or_else(
+        || struct_config.kind().default_prefix().to_string(),
+        LitStr::value,
+    );
+
+    let name = match (&params.name, &params.prefix, &params.suffix) {
+        (None, Some(prefix), Some(suffix)) => {
+            format!("{}_{}", prefix.value(), suffix.value())
+        }
+        (None, Some(prefix), None) => {
+            format!("{}_{field_name}", prefix.value())
+        }
+        (None, None, Some(suffix)) => {
+            format!("{default_prefix}_{}", suffix.value())
+        }
+        (None, None, None) => format!("{default_prefix}_{field_name}"),
+        (Some(name), Some(_), Some(_)) => {
+            return Err(Error::new(
+                name.span(),
+                format!(
+                    "'{NAME_PARAM}' param cannot be set with \
+                    '{PREFIX_PARAM}' and '{SUFFIX_PARAM}' params."
+                ),
+            ));
+        }
+        (Some(name), Some(_), None) => {
+            return Err(Error::new(
+                name.span(),
+                format!(
+                    "'{NAME_PARAM}' param cannot be set with \
+                    '{PREFIX_PARAM}' param."
+                ),
+            ));
+        }
+        (Some(name), None, Some(_)) => {
+            return Err(Error::new(
+                name.span(),
+                format!(
+                    "'{NAME_PARAM}' param cannot be set with \
+                    '{SUFFIX_PARAM}' param."
+                ),
+            ));
+        }
+        (Some(name), None, None) => name.value(),
+    };
+    let is_fallible = params.validate.is_some()
+        || !params.check_spans().is_empty()
+        || params.with_try_into.as_ref().is_some_and(LitBool::value);
+    let name = match (&params.name, is_fallible) {
+        (None, true) => format!("{TRY_PREFIX}_{name}"),
+        _ => name,
+    };
+
+    if syn::parse_str::<Ident>(&name).is_err() {
+        let span = [&params.name, &params.prefix, &params.suffix]
+            .into_iter()
+            .flatten()
+            .map(LitStr::span)
+            .next()
+            .unwrap_or_else(Span2::call_site);
+        return Err(Error::new(
+            span,
+            format!("'{name}' is not a valid method name."),
+        ));
+    }
+
+    Ok(name)
+}
+
+/// Makes declarative checks of setter. They cannot be combined with
+/// custom validator, since errors of both would need a common type.
+fn make_checks(
+    params: &RawSetterParams,
+    const_fn: bool,
+) -> Result<Vec<SetterCheck>> {
+    let check_spans = params.check_spans();
+    let Some(&span) = check_spans.first() else {
+        return Ok(Vec::new());
+    };
+    if params.validate.is_some() {
+        return Err(Error::new(
+            span,
+            format!(
+                "Declarative checks cannot be combined with \
+                '{VALIDATE_PARAM}' param."
+            ),
+        ));
+    }
+    if params.error.is_some() {
+        return Err(Error::new(
+            span,
+            format!(
+                "Declarative checks cannot be combined with \
+                '{ERROR_PARAM}' param, since their error type is \
+                generated."
+            ),
+        ));
+    }
+    if const_fn {
+        return Err(Error::new(
+            span,
+            "Declarative checks cannot be made by const setters.",
+        ));
+    }
+
+    let mut checks: Vec<SetterCheck> = Vec::new();
+    let mut errors: Vec<Error> = Vec::new();
+
+    if let Some(range) = params.range.as_ref() {
+        match range.parse::<ExprRange>() {
+            Ok(ExprRange {
+                start: None,
+                end: None,
+                ..
+            }) => errors.push(Error::new(
+                range.span(),
+                format!("'{RANGE_PARAM}' param requires at least one bound."),
+            )),
+            Ok(expr_range) => checks.push(SetterCheck::Range(expr_range)),
+            Err(error) => errors.push(error),
+        }
+    }
+    if params.non_empty.as_ref().is_some_and(LitBool::value) {
+        checks.push(SetterCheck::NonEmpty);
+    }
+    if let Some(min_len) = params.min_len.as_ref() {
+        match min_len.base10_parse() {
+            Ok(min_len) => checks.push(SetterCheck::MinLen(min_len)),
+            Err(error) => errors.push(error),
+        }
+    }
+    if let Some(max_len) = params.max_len.as_ref() {
+        match max_len.base10_parse() {
+            Ok(max_len) => checks.push(SetterCheck::MaxLen(max_len)),
+            Err(error) => errors.push(error),
+        }
+    }
+
+    combine_errors(errors)?;
+    Ok(checks)
+}
+
+/// Makes flag of fallible conversion by `TryInto` or `FromStr`. Errors
+/// of conversions have their own types, so conversions cannot be
+/// combined with validators and declarative checks.
+fn make_conversion(
+    conversion: Option<&LitBool>,
+    param_name: &str,
+    params: &RawSetterParams,
+    const_fn: bool,
+) -> Result<bool> {
+    let Some(conversion) = conversion.filter(|conversion| conversion.value())
+    else {
+        return Ok(false);
+    };
+
+    if const_fn {
+        return Err(Error::new(
+            conversion.span(),
+            format!(
+                "'{param_name}' param cannot be set to true for const \
+                setters, because conversions cannot be made in const fn."
+            ),
+        ));
+    }
+    if params.validate.is_some() || !params.check_spans().is_empty() {
+        return Err(Error::new(
+            conversion.span(),
+            format!(
+                "'{param_name}' param cannot be set to true with \
+                '{VALIDATE_PARAM}' param or declarative checks."
+            ),
+        ));
+    }
+
+    Ok(true)
+}
+
+/// Checks that const setter can assign the field. Assignment drops
+/// the previous value, which const fn cannot do for types with
+/// destructors. Errors point at `const_fn` param of the attribute or
+/// at the field type, if the setter is const by the macro param.
+fn check_const_fn(
+    field_type: &Type,
+    const_fn: bool,
+    const_fn_lit: Option<&LitBool>,
+) -> Result<()> {
+    if !const_fn || !has_destructor(field_type) {
+        return Ok(());
+    }
+
+    let message = format!(
+        "Setters of fields of types with destructors, like `String` or \
+        `Vec`, cannot be const, because const fn cannot drop previous \
+        values. Set '{CONST_FN_PARAM}' param to false for the field."
+    );
+    match const_fn_lit {
+        Some(const_fn_lit) => Err(Error::new(const_fn_lit.span(), message)),
+        None => Err(Error::new_spanned(field_type, message)),
+    }
+}
+
+/// Checks whether field type is known to have destructor. Options
+/// have one, if their inner types do.
+fn has_destructor(field_type: &Type) -> bool {
+    if let Some(inner_type) = extract_option_inner_type(field_type) {
+        return has_destructor(inner_type);
+    }
+
+    [&DROP_PATHS[..], &SEQUENCE_PATHS, &MAP_PATHS, &SET_PATHS]
+        .iter()
+        .any(|paths| has_path(field_type, paths))
+}
+
+/// Makes flag of helper, which replaces, takes or swaps the value of
+/// field by `std::mem` functions.
+fn make_mem_helper(
+    mem_helper: Option<&LitBool>,
+    param_name: &str,
+    const_fn: bool,
+) -> Result<bool> {
+    match mem_helper {
+        Some(mem_helper) if mem_helper.value() && const_fn => Err(Error::new(
+            mem_helper.span(),
+            format!(
+                "'{param_name}' param cannot be set to true for const \
+                    setters."
+            ),
+        )),
+        mem_helper => Ok(mem_helper.is_some_and(LitBool::value)),
+    }
+}
+
+/// Makes name of method, which pushes a single item into sequence
+/// field, and type of items. `each = true` names the method after
+/// the suffix, while string value names it explicitly.
+fn make_each(
+    field_type: &Type,
+    params: &RawSetterParams,
+    suffix: &str,
+    kind: SetterKind,
+    const_fn: bool,
+    with_try_into: bool,
+) -> Result<Option<(String, Type)>> {
+    let (name, span) = match params.each.as_ref() {
+        Some(Lit::Str(each)) => (each.value(), each.span()),
+        Some(Lit::Bool(each)) if each.value() => {
+            let (each_prefix, each_suffix) = kind.each_affixes();
+            (format!("{each_prefix}_{suffix}{each_suffix}"), each.span())
+        }
+        _ => return Ok(None),
+    };
+
+    if const_fn {
+        return Err(Error::new(
+            span,
+            format!(
+                "'{EACH_PARAM}' param cannot be set for const setters, \
+                because iterators cannot be collected in const fn."
+            ),
+        ));
+    }
+    if with_try_into {
+        return Err(Error::new(
+            span,
+            format!(
+                "'{EACH_PARAM}' param cannot be set with \
+                '{WITH_TRY_INTO_PARAM}' param."
+            ),
+        ));
+    }
+    if syn::parse_str::<Ident>(&name).is_err() {
+        return Err(Error::new(
+            span,
+            format!("'{name}' is not a valid method name."),
+        ));
+    }
+
+    let item_type = match params.sequence.as_ref() {
+        None => extract_sequence_item_type(field_type),
+        Some(sequence) if !sequence.value() => None,
+        Some(_) => extract_first_type_argument(field_type),
+    };
+    match item_type {
+        Some(item_type) => Ok(Some((name, item_type.clone()))),
+        None => Err(Error::new(
+            span,
+            format!(
+                "'{EACH_PARAM}' param can be set only for sequence fields \
+                like `Vec<T>`. Set '{SEQUENCE_PARAM}' param to true for \
+                other types with item type as their first generic \
+                argument."
+            ),
+        )),
+    }
+}
+
+/// Makes singular name of entries of map or set field and types of
+/// the entries, which are taken from generic arguments of the field.
+fn make_keyed_collection(
+    field_type: &Type,
+    singular: Option<&LitStr>,
+    const_fn: bool,
+) -> Result<Option<(String, KeyedCollection)>> {
+    let Some(singular) = singular else {
+        return Ok(None);
+    };
+
+    if const_fn {
+        return Err(Error::new(
+            singular.span(),
+            format!(
+                "'{SINGULAR_PARAM}' param cannot be set for const \
+                setters, because entries cannot be inserted in const fn."
+            ),
+        ));
+    }
+    // Singular name is only a part of method names, so it's checked
+    // with a leading underscore, e.g. `type` is a valid one.
+    let name = singular.value();
+    if syn::parse_str::<Ident>(&format!("_{name}")).is_err() {
+        return Err(Error::new(
+            singular.span(),
+            format!("'{name}' cannot be used in method names."),
+        ));
+    }
+
+    let type_arguments = extract_type_arguments(field_type);
+    let keyed_collection = match type_arguments.as_slice() {
+        [key_type, value_type, ..] if has_path(field_type, &MAP_PATHS) => {
+            KeyedCollection::Map {
+                key_type: (*key_type).clone(),
+                value_type: Box::new((*value_type).clone()),
+            }
+        }
+        [item_type, ..] if has_path(field_type, &SET_PATHS) => {
+            KeyedCollection::Set {
+                item_type: (*item_type).clone(),
+            }
+        }
+        _ => {
+            return Err(Error::new(
+                singular.span(),
+                format!(
+                    "'{SINGULAR_PARAM}' param can be set only for map and \
+                    set fields like `HashMap<K, V>` and `HashSet<T>`."
+                ),
+            ));
+        }
+    };
+
+    Ok(Some((name, keyed_collection)))
+}
+
+/// Makes change of type parameter, which is the type of the field.
+/// Other fields are moved into the struct of the new type as is, so
+/// the type parameter cannot be used by them.
+fn make_type_change(
+    field_type: &Type,
+    member: &Member,
+    params: &RawSetterParams,
+    const_fn: bool,
+    receiver: Option<Receiver>,
+    struct_shape: &StructShape,
+) -> Result<Option<TypeChange>> {
+    let Some(change_type) = params
+        .change_type
+        .as_ref()
+        .filter(|change_type| change_type.value())
+    else {
+        return Ok(None);
+    };
+    let span = change_type.span();
+
+    if receiver == Some(Receiver::RefMut) {
+        return Err(Error::new(
+            span,
+            format!(
+                "'{CHANGE_TYPE_PARAM}' param cannot be set to true for \
+                setters with '{RECEIVER_PARAM}' set to 'ref_mut', because \
+                type of borrowed struct cannot be changed."
+            ),
+        ));
+    }
+    if const_fn {
+        return Err(Error::new(
+            span,
+            format!(
+                "'{CHANGE_TYPE_PARAM}' param cannot be set to true for \
+                const setters."
+            ),
+        ));
+    }
+    let with_into = params.with_into.as_ref().is_some_and(LitBool::value);
+    let with_try_into =
+        params.with_try_into.as_ref().is_some_and(LitBool::value);
+    let from_str = params.from_str.as_ref().is_some_and(LitBool::value);
+    if with_into
+        || with_try_into
+        || from_str
+        || params.validate.is_some()
+        || !params.check_spans().is_empty()
+    {
+        return Err(Error::new(
+            span,
+            format!(
+                "'{CHANGE_TYPE_PARAM}' param cannot be set to true with \
+                conversions, validators or declarative checks, since the \
+                setter takes values of any type."
+            ),
+        ));
+    }
+
+    let type_param = match field_type {
+        Type::Path(type_path) if type_path.qself.is_none() => {
+            type_path.path.get_ident().filter(|ident| {
+                struct_shape.generics.params.iter().any(|param| {
+                    matches!(param, GenericParam::Type(type_param)
+                        if type_param.ident == **ident)
+                })
+            })
+        }
+        _ => None,
+    };
+    let Some(type_param) = type_param else {
+        return Err(Error::new(
+            span,
+            format!(
+                "'{CHANGE_TYPE_PARAM}' param requires field type to be a \
+                type parameter of the struct."
+            ),
+        ));
+    };
+
+    let mut other_members: Vec<Member> = Vec::new();
+    for (other_member, other_type) in &struct_shape.fields {
+        if other_member == member {
+            continue;
+        }
+        if contains_ident(other_type.to_token_stream(), type_param) {
+            return Err(Error::new(
+                span,
+                format!(
+                    "'{CHANGE_TYPE_PARAM}' param requires type parameter \
+                    `{type_param}` to be used only by this field."
+                ),
+            ));
+        }
+        other_members.push(other_member.clone());
+    }
+
+    Ok(Some(TypeChange {
+        type_param: type_param.clone(),
+        other_members,
+    }))
+}
+
+/// Checks whether tokens contain identifier, including nested groups.
+pub fn contains_ident(tokens: TokenStream2, ident: &Ident) -> bool {
+    tokens.into_iter().any(|token| match token {
+        TokenTree::Ident(other) => other == *ident,
+        TokenTree::Group(group) => contains_ident(group.stream(), ident),
+        _ => false,
+    })
+}
+
+/// Makes inner type of `Option` field. Types are recognised as
+/// `Option` by their paths, unless `option` param overrides it, e.g.
+/// for aliases of `Option`.
+fn make_option_inner_type(
+    field_type: &Type,
+    option: Option<&LitBool>,
+) -> Result<Option<Type>> {
+    match option {
+        None => Ok(extract_option_inner_type(field_type).cloned()),
+        Some(option) if !option.value() => Ok(None),
+        Some(option) => match extract_first_type_argument(field_type) {
+            Some(inner_type) => Ok(Some(inner_type.clone())),
+            None => Err(Error::new(
+                option.span(),
+                format!(
+                    "'{OPTION_PARAM}' param requires field type with \
+                    inner type as its first generic argument."
+                ),
+            )),
+        },
+    }
+}
+
+fn extract_option_inner_type(field_type: &Type) -> Option<&Type> {
+    extract_inner_type(field_type, &OPTION_PATHS)
+}
+
+fn extract_sequence_item_type(field_type: &Type) -> Option<&Type> {
+    extract_inner_type(field_type, &SEQUENCE_PATHS)
+}
+
+/// Extracts first generic argument of field type, if the type has
+/// one of `paths`.
+fn extract_inner_type<'a>(
+    field_type: &'a Type,
+    paths: &[&[&str]],
+) -> Option<&'a Type> {
+    if has_path(field_type, paths) {
+        return extract_first_type_argument(field_type);
+    }
+    None
+}
+
+fn has_path(field_type: &Type, paths: &[&[&str]]) -> bool {
+    let Type::Path(type_path) = field_type else {
+        return false;
+    };
+    if type_path.qself.is_some() {
+        return false;
+    }
+
+    let path = &type_path.path;
+    let segments: Vec<String> = path
+        .segments
+        .iter()
+        .map(|segment| segment.ident.to_string())
+        .collect();
+    // `::Option` refers to a crate named `Option`, not the type.
+    paths.iter().any(|known_path| {
+        *known_path == segments.as_slice()
+            && (path.leading_colon.is_none() || known_path.len() > 1)
+    })
+}
+
+fn extract_first_type_argument(field_type: &Type) -> Option<&Type> {
+    extract_type_arguments(field_type).first().copied()
+}
+
+/// Extracts leading generic type arguments of field type. Arguments
+/// after lifetimes and consts are skipped.
+fn extract_type_arguments(field_type: &Type) -> Vec<&Type> {
+    let Type::Path(type_path) = field_type else {
+        return Vec::new();
+    };
+    let Some(PathArguments::AngleBracketed(args)) = type_path
+        .path
+        .segments
+        .last()
+        .map(|segment| &segment.arguments)
+    else {
+        return Vec::new();
+    };
+
+    args.args
+        .iter()
+        .map_while(|arg| match arg {
+            GenericArgument::Type(arg_type) => Some(arg_type),
+            _ => None,
+        })
+        .collect()
+}
+
+pub fn make_member(field: &Field, index: usize) -> Member {
+    match &field.ident {
+        Some(field_ident) => Member::Named(field_ident.clone()),
+        None => Member::Unnamed(Index::from(index)),
+    }
+}
+
+/// Makes name of field, which is used in names of its methods.
+pub(crate) fn make_field_name(member: &Member) -> String {
+    match member {
+        Member::Named(field_ident) => field_ident.unraw().to_string(),
+        Member::Unnamed(index) => index.index.to_string(),
+    }
+}
+
+fn make_default_visibility(struct_config: &StructConfig) -> Visibility {
+    struct_config
+        .visibility()
+        .cloned()
+        .unwrap_or_else(default_visibility_factory)
+}
+
+/// Setters use `impl Into<T>` by default, unless it's disabled for the
+/// whole struct or setter is const.
+fn make_default_with_into(
+    const_fn: bool,
+    struct_config: &StructConfig,
+) -> bool {
+    !const_fn && struct_config.with_into().unwrap_or(true)
+}
+
+fn default_visibility_factory() -> Visibility {
+    let span = Span2::call_site();
+    let pub_token = Token![pub](span);
+    Visibility::Public(pub_token)
+}
+
+fn remove_attributes(field: &mut Field, kind: SetterKind) {
+    field.attrs.retain(|attr| {
+        let path = attr.path();
+        !(path.is_ident(kind.disable_attribute())
+            || path.is_ident(kind.config_attribute()))
+    });
+}
+
+/// Combines errors into one, so all of them are reported together.
+pub(crate) fn combine_errors(errors: Vec<Error>) -> Result<()> {
+    let combined = errors.into_iter().reduce(|mut combined, error| {
+        combined.combine(error);
+        combined
+    });
+
+    match combined {
+        Some(error) => Err(error),
+        None => Ok(()),
+    }
+}
@@ -0,0 +1,89 @@
+use syn::{
+    Error,
+    Ident,
+    Result,
+};
+
+/// Kind of setters, which configs are parsed for. Basic setters take
+/// `&mut self`, while builder setters take and return the struct, so
+/// their attributes, default names and supported params differ.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SetterKind {
+    Basic,
+    Builder,
+}
+
+impl SetterKind {
+    /// Returns name of the kind, which errors refer to.
+    pub fn name(self) -> &'static str {
+        match self {
+            Self::Basic => "basic",
+            Self::Builder => "builder",
+        }
+    }
+
+    /// Returns name of attribute, which configures setters of field.
+    pub fn config_attribute(self) -> &'static str {
+        match self {
+            Self::Basic => "basic_setter",
+            Self::Builder => "builder_setter",
+        }
+    }
+
+    /// Returns name of attribute, which disables setters of field.
+    pub fn disable_attribute(self) -> &'static str {
+        match self {
+            Self::Basic => "disable_basic_setters",
+            Self::Builder => "disable_builder_setters",
+        }
+    }
+
+    pub fn default_prefix(self) -> &'static str {
+        match self {
+            Self::Basic => "set",
+            Self::Builder => "with",
+        }
+    }
+
+    /// Returns suffix of error enum of fallible setters, which is
+    /// appended to name of the struct, e.g. `ConfigSetError`.
+    pub fn error_enum_suffix(self) -> &'static str {
+        match self {
+            Self::Basic => "SetError",
+            Self::Builder => "WithError",
+        }
+    }
+
+    /// Checks that setters of the kind support the param. Params
+    /// listed with another kind are supported only by it.
+    pub fn check_param(
+        self,
+        param_ident: &Ident,
+        exclusive_params: &[(&str, SetterKind)],
+    ) -> Result<()> {
+        let exclusive_kind = exclusive_params
+            .iter()
+            .find(|(param_name, _)| param_ident == param_name)
+            .map(|(_, kind)| *kind);
+
+        match exclusive_kind {
+            Some(kind) if kind != self => Err(Error::new_spanned(
+                param_ident,
+                format!(
+                    "'{param_ident}' param is supported only by {} setters.",
+                    kind.name(),
+                ),
+            )),
+            _ => Ok(()),
+        }
+    }
+
+    /// Returns prefix and suffix of method, which pushes a single item
+    /// into sequence field, e.g. `push_tags` or `with_tags_item`.
+    pub fn each_affixes(self) -> (&'static str, &'static str) {
+        match self {
+            Self::Basic => ("push", ""),
+            Self::Builder => ("with", "_item"),
+        }
+    }
+}
@@ -0,0 +1,301 @@
+use syn::{
+    Error,
+    Expr,
+    ExprLit,
+    Ident,
+    Lit,
+    LitBool,
+    LitStr,
+    MetaNameValue,
+    Result,
+    Token,
+    Visibility,
+    punctuated::Punctuated,
+};
+
+use super::{
+    setter_configs::combine_errors,
+    setter_kind::SetterKind,
+};
+
+const PREFIX_PARAM: &str = "prefix";
+const VISIBILITY_PARAM: &str = "visibility";
+const WITH_INTO_PARAM: &str = "with_into";
+const CONST_FN_PARAM: &str = "const_fn";
+const STRIP_OPTION_PARAM: &str = "strip_option";
+const RECEIVER_PARAM: &str = "receiver";
+const TYPESTATE_PARAM: &str = "typestate";
+
+const OWNED_RECEIVER: &str = "owned";
+const REF_MUT_RECEIVER: &str = "ref_mut";
+/// Params supported only by builder setters. Basic setters always
+/// take `&mut self` and have no builder to track set fields.
+const EXCLUSIVE_PARAMS: [(&str, SetterKind); 2] = [
+    (RECEIVER_PARAM, SetterKind::Builder),
+    (TYPESTATE_PARAM, SetterKind::Builder),
+];
+
+/// Config of the whole struct, which is made from params passed to
+/// the macro itself. Its values are defaults for every setter, which
+/// doesn't set them explicitly.
+#[derive(Debug)]
+pub struct StructConfig {
+    kind: SetterKind,
+    prefix: Option<LitStr>,
+    visibility: Option<Visibility>,
+    with_into: Option<bool>,
+    const_fn: bool,
+    strip_option: bool,
+    receiver: Receiver,
+    typestate: bool,
+}
+
+/// Receiver of builder setters. Setters taking `self` by value return
+/// it, while setters taking `&mut self` return the reference, so they
+/// can be chained on borrowed structs.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Receiver {
+    #[default]
+    Owned,
+    RefMut,
+}
+
+/// Raw values of macro params. Literals are kept as is, so errors can
+/// point at them.
+#[derive(Debug, Default)]
+struct RawStructParams {
+    prefix: Option<LitStr>,
+    visibility: Option<LitStr>,
+    with_into: Option<LitBool>,
+    const_fn: Option<LitBool>,
+    strip_option: Option<LitBool>,
+    receiver: Option<LitStr>,
+    typestate: Option<LitBool>,
+}
+
+impl StructConfig {
+    /// Makes config with default values, which is used when params of
+    /// the macro are invalid.
+    pub fn new(kind: SetterKind) -> Self {
+        Self {
+            kind,
+            prefix: None,
+            visibility: None,
+            with_into: None,
+            const_fn: false,
+            strip_option: true,
+            receiver: Receiver::default(),
+            typestate: false,
+        }
+    }
+
+    pub fn kind(&self) -> SetterKind {
+        self.kind
+    }
+
+    pub fn prefix(&self) -> Option<&LitStr> {
+        self.prefix.as_ref()
+    }
+
+    pub fn visibility(&self) -> Option<&Visibility> {
+        self.visibility.as_ref()
+    }
+
+    pub fn with_into(&self) -> Option<bool> {
+        self.with_into
+    }
+
+    pub fn const_fn(&self) -> bool {
+        self.const_fn
+    }
+
+    pub fn strip_option(&self) -> bool {
+        self.strip_option
+    }
+
+    pub fn receiver(&self) -> Receiver {
+        self.receiver
+    }
+
+    /// Returns whether setters belong to a separate typestate builder
+    /// instead of the struct itself.
+    pub fn typestate(&self) -> bool {
+        self.typestate
+    }
+}
+
+pub fn make_struct_config(
+    params: Punctuated<MetaNameValue, Token![,]>,
+    kind: SetterKind,
+) -> Result<StructConfig> {
+    let mut raw_params = RawStructParams::default();
+    let mut errors: Vec<Error> = Vec::new();
+
+    for param in params {
+        if let Err(error) = parse_param(param, &mut raw_params, kind) {
+            errors.push(error);
+        }
+    }
+    combine_errors(errors)?;
+
+    let prefix = match raw_params.prefix {
+        // Prefix is joined with field names, so it's enough to check
+        // it with any of them.
+        Some(prefix)
+            if syn::parse_str::<Ident>(&format!("{}_x", prefix.value()))
+                .is_err() =>
+        {
+            Err(Error::new(
+                prefix.span(),
+                format!("'{}' is not a valid method prefix.", prefix.value()),
+            ))
+        }
+        prefix => Ok(prefix),
+    };
+    let visibility = raw_params
+        .visibility
+        .as_ref()
+        .map(LitStr::parse)
+        .transpose();
+    let const_fn = raw_params.const_fn.as_ref().is_some_and(LitBool::value);
+    let with_into = match raw_params.with_into.as_ref() {
+        Some(with_into) if const_fn && with_into.value() => Err(Error::new(
+            with_into.span(),
+            format!(
+                "'{WITH_INTO_PARAM}' param cannot be set to true for \
+                const setters, because `Into::into` cannot be called \
+                in const fn."
+            ),
+        )),
+        with_into => Ok(with_into.map(LitBool::value)),
+    };
+    let receiver = raw_params
+        .receiver
+        .as_ref()
+        .map_or(Ok(Receiver::default()), parse_receiver);
+    let typestate = match raw_params.typestate.as_ref() {
+        // Setters of typestate builders change its type, so they
+        // cannot be const or take `&mut self`.
+        Some(typestate)
+            if typestate.value()
+                && (const_fn || matches!(receiver, Ok(Receiver::RefMut))) =>
+        {
+            Err(Error::new(
+                typestate.span(),
+                format!(
+                    "'{TYPESTATE_PARAM}' param cannot be set to true with \
+                    '{CONST_FN_PARAM}' set to true or '{RECEIVER_PARAM}' \
+                    set to '{REF_MUT_RECEIVER}'."
+                ),
+            ))
+        }
+        typestate => Ok(typestate.is_some_and(LitBool::value)),
+    };
+
+    let errors = [
+        prefix.as_ref().err(),
+        visibility.as_ref().err(),
+        with_into.as_ref().err(),
+        receiver.as_ref().err(),
+        typestate.as_ref().err(),
+    ];
+    combine_errors(errors.into_iter().flatten().cloned().collect())?;
+
+    Ok(StructConfig {
+        kind,
+        prefix: prefix?,
+        visibility: visibility?,
+        with_into: with_into?,
+        const_fn,
+        strip_option: raw_params
+            .strip_option
+            .as_ref()
+            .is_none_or(LitBool::value),
+        receiver: receiver?,
+        typestate: typestate?,
+    })
+}
+
+/// Parses value of `receiver` param, which is shared by the macro and
+/// config attributes of fields.
+pub fn parse_receiver(receiver: &LitStr) -> Result<Receiver> {
+    match receiver.value().as_str() {
+        OWNED_RECEIVER => Ok(Receiver::Owned),
+        REF_MUT_RECEIVER => Ok(Receiver::RefMut),
+        value => Err(Error::new(
+            receiver.span(),
+            format!(
+                "Unexpected receiver '{value}'. Expected \
+                '{OWNED_RECEIVER}' or '{REF_MUT_RECEIVER}'."
+            ),
+        )),
+    }
+}
+
+fn parse_param(
+    param: MetaNameValue,
+    raw_params: &mut RawStructParams,
+    kind: SetterKind,
+) -> Result<()> {
+    let Some(param_ident) = param.path.get_ident() else {
+        return Err(Error::new_spanned(&param.path, "Unexpected param."));
+    };
+    kind.check_param(param_ident, &EXCLUSIVE_PARAMS)?;
+    let Expr::Lit(ExprLit { lit, .. }) = param.value else {
+        return Err(Error::new_spanned(
+            &param.value,
+            "Expected literal value.",
+        ));
+    };
+
+    match (param_ident.to_string().as_str(), lit) {
+        (PREFIX_PARAM, Lit::Str(value)) => {
+            set_param(&mut raw_params.prefix, value, param_ident)
+        }
+        (VISIBILITY_PARAM, Lit::Str(value)) => {
+            set_param(&mut raw_params.visibility, value, param_ident)
+        }
+        (WITH_INTO_PARAM, Lit::Bool(value)) => {
+            set_param(&mut raw_params.with_into, value, param_ident)
+        }
+        (CONST_FN_PARAM, Lit::Bool(value)) => {
+            set_param(&mut raw_params.const_fn, value, param_ident)
+        }
+        (STRIP_OPTION_PARAM, Lit::Bool(value)) => {
+            set_param(&mut raw_params.strip_option, value, param_ident)
+        }
+        (RECEIVER_PARAM, Lit::Str(value)) => {
+            set_param(&mut raw_params.receiver, value, param_ident)
+        }
+        (TYPESTATE_PARAM, Lit::Bool(value)) => {
+            set_param(&mut raw_params.typestate, value, param_ident)
+        }
+        (PREFIX_PARAM | VISIBILITY_PARAM | RECEIVER_PARAM, lit) => {
+            Err(Error::new_spanned(lit, "Expected string value."))
+        }
+        (
+            WITH_INTO_PARAM | CONST_FN_PARAM | STRIP_OPTION_PARAM
+            | TYPESTATE_PARAM,
+            lit,
+        ) => Err(Error::new_spanned(lit, "Expected bool value.")),
+        _ => Err(Error::new_spanned(
+            param_ident,
+            format!("Unexpected param '{param_ident}'."),
+        )),
+    }
+}
+
+fn set_param<T>(
+    param: &mut Option<T>,
+    value: T,
+    param_ident: &Ident,
+) -> Result<()> {
+    if param.is_some() {
+        return Err(Error::new_spanned(
+            param_ident,
+            format!("Duplicate '{param_ident}' param."),
+        ));
+    }
+    *param = Some(value);
+    Ok(())
+}
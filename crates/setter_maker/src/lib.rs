@@ -2,9 +2,14 @@
 use proc_macro::TokenStream;
 use syn::{
     ItemStruct,
+    MetaNameValue,
+    Token,
     parse_macro_input,
+    punctuated::Punctuated,
 };
 
+mod config;
+
 mod builder_setters;
 use builder_setters::do_make_builder_setters;
 
@@ -17,18 +22,41 @@ use basic_setters::do_make_basic_setters;
 /// accepts any type implementing `Into<T>` (where `T` is the field type),
 /// and returns a modified instance.
 ///
-/// Apply this macro to a struct with named fields using
+/// Apply this macro to a struct using
 /// `#[make_builder_setters]`. The original struct remains unchanged,
 /// and setters provide a convenient way to set field values in a
 /// chainable manner.
 ///
+/// Params of the macro itself set defaults for every setter of the
+/// struct, which `prefix`, `visibility`, `with_into`, `const_fn`,
+/// `strip_option` and `receiver` options of field attributes
+/// override, e.g.
+/// `#[make_builder_setters(prefix = "set", visibility = "pub(crate)")]`.
+/// Unknown params are reported as errors.
+///
+/// With `receiver = "ref_mut"` setters take `&mut self` and return
+/// `&mut Self` instead of taking and returning `self`, so they can be
+/// chained on structs behind `&mut`. Such setters are not
+/// `#[must_use]`, since calling them alone is fine.
+///
+/// With `typestate = true` setters belong to a separate builder
+/// instead, which checks at compile time that required fields are set.
+/// See [Typestate builder](#typestate-builder).
+///
 /// Multiple `#[builder_setter]` attributes can be applied to a
 /// single field, generating multiple setter methods with the specified
 /// configurations.
+/// Helpers are named after the field, so attributes, which make the
+/// same helpers, must rename them by `suffix`, `each` or `singular`.
+/// Methods made by several attributes are reported as errors.
+///
+/// Fields of tuple structs get setters only from explicit attributes,
+/// e.g. `#[builder_setter(name = "with_x")]` on field `0`, or a bare
+/// `#[builder_setter]` for `with_0`. Unit structs get no setters.
 ///
 /// ### Customization Options
 /// - `#[disable_builder_setters]`: Skip setters generation for a
-///    specific field.
+///   specific field.
 ///
 /// - `#[builder_setter(
 ///       name = "<name>",
@@ -36,16 +64,130 @@ use basic_setters::do_make_basic_setters;
 ///       prefix = "<prefix>",
 ///       suffix = "<suffix>",
 ///       with_into = true|false,
+///       with_try_into = true|false,
+///       from_str = true|false,
+///       const_fn = true|false,
+///       receiver = "owned"|"ref_mut",
+///       strip_option = true|false,
+///       option = true|false,
+///       option_helpers = true|false,
+///       update = true|false,
+///       change_type = true|false,
+///       each = true|"<name>",
+///       sequence = true|false,
+///       singular = "<name>",
+///       validate = "<path>",
+///       error = "<type>",
+///       range = "<range>",
+///       non_empty = true|false,
+///       min_len = <len>,
+///       max_len = <len>,
+///       doc = "<doc>",
 ///    )]`:
 ///
 ///    Configure the setter with the following options:
 ///    - `name`: Set a custom method name, overriding prefix/suffix.
 ///    - `visibility`: Override method visibility. Set to "" for
-///      `pub(self)`. Default: `pub`.
-///    - `prefix`: Override the prefix. Default: "with".
+///      `pub(self)`. Default: macro param or `pub`.
+///    - `prefix`: Override the prefix. Default: macro param or "with".
 ///    - `suffix`: Override the suffix. Default: field name.
 ///    - `with_into`: Whether to use the `impl Into<T>` in method
-///       parameters. Default: true.
+///      parameters. Default: macro param or true, but false for
+///      const setters.
+///    - `with_try_into`: Whether the setter is fallible
+///      `try_<prefix>_<suffix>(self, ..)` taking values of any type
+///      implementing `TryInto<T>` and returning `Result<Self, E>` with
+///      the conversion error. Cannot be used with `with_into = true`.
+///      Default: false.
+///    - `from_str`: Whether to generate also `<name>_str(self, &str)`
+///      returning `Result<Self, E>`, which parses the value by
+///      `FromStr`, e.g. `with_port_str`. Default: false.
+///
+///      These conversions cannot be used with `validate`, declarative
+///      checks or `const_fn = true`.
+///    - `const_fn`: Whether to generate `const fn`. Cannot be used
+///      with `with_into = true`, so it changes default of `with_into`
+///      to false. Setters of fields of types with destructors, like
+///      `String`, `Vec`, `Box` or `Option`s of them, cannot be const,
+///      since const fn cannot drop previous values. Other types with
+///      destructors are not recognised and fail with error E0493.
+///      Default: macro param or false.
+///    - `receiver`: Whether the setter and its helpers take `self`
+///      ("owned") or `&mut self` ("ref_mut"). Default: macro param or
+///      "owned".
+///    - `strip_option`: Whether setters of `Option<T>` fields take
+///      `T` and wrap it in `Some`. If false, they take `Option<T>`,
+///      so the field can be set to `None`. Only the outer option is
+///      stripped, so setters of `Option<Option<T>>` take `Option<T>`.
+///      Default: macro param or true.
+///    - `option`: Whether the field is an option for `strip_option`.
+///      Fields of `Option`, `std::option::Option` and
+///      `core::option::Option` types are recognised by default. Set
+///      to true for aliases like `type Maybe<T> = Option<T>`, whose
+///      first generic argument is taken as the inner type.
+///    - `option_helpers`: Whether to generate `without_<suffix>(self)`
+///      returning `self` with the `Option` field set to `None`. It
+///      has visibility of the setter. Default: false.
+///    - `update`: Whether to generate `map_<suffix>(self, f)`, which
+///      returns `self` with the value changed by
+///      `f: impl FnOnce(T) -> T`. With `receiver = "ref_mut"` values
+///      cannot be moved out, so `f: impl FnOnce(&mut T)` changes them
+///      in place. `f` gets inner values of `Option` fields and is
+///      called only when they are `Some`, unless `strip_option` is
+///      false. Cannot be used with `const_fn = true`. Default: false.
+///    - `change_type`: Whether the setter of field of type parameter
+///      `T` takes value of any type `U` and returns the struct with
+///      `U` in place of `T`, e.g. `with_inner<U>(self, inner: U) ->
+///      Foo<U>`, moving all other fields across. Bounds of `T` and
+///      where predicates mentioning it must hold for `U` too. `T`
+///      cannot be used by other fields. Cannot be used with
+///      conversions, checks, `const_fn = true` or
+///      `receiver = "ref_mut"`. Default: false.
+///    - `each`: Whether to generate `with_<suffix>_item(self, item)`
+///      and `with_<suffix>_items(self, iter)` returning `self` with
+///      items pushed into the sequence field. The setter takes
+///      `impl IntoIterator` of items then. String value names the
+///      push method instead, e.g. "add_item". Items are taken as
+///      `impl Into<T>` like values with `with_into`. Default: false.
+///    - `sequence`: Whether the field is a sequence for `each`. Fields
+///      of `Vec`, `VecDeque` and `LinkedList` types are recognised by
+///      default. Set to true for other collections implementing
+///      `Extend` and `FromIterator`, whose first generic argument is
+///      taken as the item type.
+///    - `singular`: Singular name of entries of map or set field,
+///      e.g. "header", which enables `with_<singular>(self, key,
+///      value)` for `HashMap` and `BTreeMap` fields and
+///      `with_<singular>(self, item)` for `HashSet` and `BTreeSet`
+///      fields returning `self` with the entry inserted. Entries are
+///      taken as `impl Into<T>` like values with `with_into`.
+///    - `validate`: Path of function `fn(&T) -> Result<(), E>`, which
+///      validates values before they are set. Deref coercion applies,
+///      so it can take `&str` for `String` fields. The setter becomes
+///      fallible `try_<prefix>_<suffix>(self, ..) -> Result<Self, E>`
+///      and sets the value only if it passes validation. Cannot be
+///      used with `const_fn = true`.
+///    - `error`: Error type of the fallible setter, which errors of
+///      the validator are converted into by `?`. It cannot be inferred
+///      from the validator, so it's required with `validate`.
+///    - `range`: Range expression like "1..=65535", which values must
+///      be contained in.
+///    - `non_empty`: Whether values must not be empty by `is_empty`.
+///    - `min_len`, `max_len`: Bounds of lengths of values by `len`.
+///
+///      These checks make the setter fallible like `validate` does,
+///      but its errors are variants of the generated enum
+///      `<Struct>WithError` with visibility of the struct, e.g.
+///      `FooWithError::PortOutOfRange` for the `port` field. They
+///      cannot be used with `validate`, `error` or `const_fn`.
+///
+///      The enum derives `Debug`, `Clone` and `PartialEq`, so values
+///      checked by `range` must implement them besides `PartialOrd`.
+///      Setters of the same field share variants, so their `range`
+///      params must use the same kind of range, e.g. "1..10" and
+///      "2..5", and the same type of values. The enum has no
+///      generics, so the type cannot use generics of the struct.
+///    - `doc`: Override docs of the method. Default: generated lead
+///      line followed by docs of the field.
 ///
 /// # Example
 /// ```rust
@@ -97,10 +239,89 @@ use basic_setters::do_make_basic_setters;
 ///
 /// assert_eq!(foo, expected);
 /// ```
+///
+/// # Typestate builder
+/// `#[make_builder_setters(typestate = true)]` generates
+/// `<Struct>Builder` with a state type parameter for every field
+/// marked by `#[required]`, e.g. `HostState` for `host`. The state is
+/// `()` until the field is set and `(T,)` holding the value afterwards,
+/// so `build()` exists only when every required field is set, and
+/// forgetting one is a compile error. Setters of required fields exist
+/// only while they are unset. `<Struct>::builder()` returns the
+/// builder with no fields set, and fields not set by it get their
+/// `Default` values.
+///
+/// Setters of the builder are named as usual, e.g. `with_host`, and
+/// support only `name`, `prefix`, `suffix`, `visibility`,
+/// `with_into`, `strip_option`, `option` and `doc` options. Required
+/// fields must have setters, and the struct gets no setters itself.
+/// `typestate = true` cannot be used with `const_fn = true` or
+/// `receiver = "ref_mut"`.
+/// ```rust
+/// use useless_setter_maker::make_builder_setters;
+///
+/// #[make_builder_setters(typestate = true)]
+/// #[derive(Debug, PartialEq)]
+/// struct Server {
+///     #[required]
+///     host: String,
+///     #[required]
+///     port: u16,
+///     timeout: Option<u32>,
+/// }
+///
+/// let server = Server::builder()
+///     .with_port(8080_u16)
+///     .with_host("localhost")
+///     .build();
+///
+/// let expected = Server {
+///     host: String::from("localhost"),
+///     port: 8080,
+///     timeout: None,
+/// };
+///
+/// assert_eq!(server, expected);
+/// ```
+///
+/// ```compile_fail
+/// use useless_setter_maker::make_builder_setters;
+///
+/// #[make_builder_setters(typestate = true)]
+/// struct Server {
+///     #[required]
+///     host: String,
+///     #[required]
+///     port: u16,
+/// }
+///
+/// let server = Server::builder().with_host("localhost").build();  // No `port`
+/// ```
+///
+/// # Errors
+/// Invalid params are reported as described in [Errors](crate#errors).
+/// ```compile_fail
+/// use useless_setter_maker::make_builder_setters;
+///
+/// #[make_builder_setters]
+/// struct Foo {
+///     #[builder_setter(prefix = 1)]                // Expected string value
+///     bar: u16,
+///
+///     #[builder_setter(name = "a", suffix = "b")]  // Conflicting params
+///     baz: String,
+/// }
+/// ```
 #[proc_macro_attribute]
-pub fn make_builder_setters(_: TokenStream, item: TokenStream) -> TokenStream {
+pub fn make_builder_setters(
+    params: TokenStream,
+    item: TokenStream,
+) -> TokenStream {
+    let params = parse_macro_input!(
+        params with Punctuated::<MetaNameValue, Token![,]>::parse_terminated
+    );
     let item = parse_macro_input!(item as ItemStruct);
-    do_make_builder_setters(item)
+    do_make_builder_setters(params, item)
 }
 
 /// Generates basic setter methods for struct fields. Each setter
@@ -108,17 +329,30 @@ pub fn make_builder_setters(_: TokenStream, item: TokenStream) -> TokenStream {
 /// accepts any type implementing `Into<T>` (where `T` is the field type),
 /// and returns a modified instance.
 ///
-/// Apply this macro to a struct with named fields using
+/// Apply this macro to a struct using
 /// `#[make_basic_setters]`. The original struct remains unchanged,
 /// and setters provide a convenient way to set field values..
 ///
+/// Params of the macro itself set defaults for every setter of the
+/// struct, which `prefix`, `visibility`, `with_into`, `const_fn` and
+/// `strip_option` options of field attributes override, e.g.
+/// `#[make_basic_setters(prefix = "with", visibility = "pub(crate)")]`.
+/// Unknown params are reported as errors.
+///
 /// Multiple `#[basic_setter]` attributes can be applied to a
 /// single field, generating multiple setter methods with the specified
 /// configurations.
+/// Helpers are named after the field, so attributes, which make the
+/// same helpers, must rename them by `suffix`, `each` or `singular`.
+/// Methods made by several attributes are reported as errors.
+///
+/// Fields of tuple structs get setters only from explicit attributes,
+/// e.g. `#[basic_setter(name = "set_x")]` on field `0`, or a bare
+/// `#[basic_setter]` for `set_0`. Unit structs get no setters.
 ///
 /// ### Customization Options
 /// - `#[disable_basic_setters]`: Skip setters generation for a
-///    specific field.
+///   specific field.
 ///
 /// - `#[basic_setter(
 ///       name = "<name>",
@@ -126,16 +360,138 @@ pub fn make_builder_setters(_: TokenStream, item: TokenStream) -> TokenStream {
 ///       prefix = "<prefix>",
 ///       suffix = "<suffix>",
 ///       with_into = true|false,
+///       with_try_into = true|false,
+///       from_str = true|false,
+///       const_fn = true|false,
+///       strip_option = true|false,
+///       option = true|false,
+///       option_helpers = true|false,
+///       replace = true|false,
+///       take = true|false,
+///       swap = true|false,
+///       update = true|false,
+///       each = true|"<name>",
+///       sequence = true|false,
+///       singular = "<name>",
+///       validate = "<path>",
+///       error = "<type>",
+///       range = "<range>",
+///       non_empty = true|false,
+///       min_len = <len>,
+///       max_len = <len>,
+///       doc = "<doc>",
 ///    )]`:
 ///
 ///    Configure the setter with the following options:
 ///    - `name`: Set a custom method name, overriding prefix/suffix.
 ///    - `visibility`: Override method visibility. Set to "" for
-///      `pub(self)`. Default: `pub`.
-///    - `prefix`: Override the prefix. Default: "set".
+///      `pub(self)`. Default: macro param or `pub`.
+///    - `prefix`: Override the prefix. Default: macro param or "set".
 ///    - `suffix`: Override the suffix. Default: field name.
 ///    - `with_into`: Whether to use the `impl Into<T>` in method
-///       parameters. Default: true.
+///      parameters. Default: macro param or true, but false for
+///      const setters.
+///    - `with_try_into`: Whether the setter is fallible
+///      `try_<prefix>_<suffix>(&mut self, ..)` taking values of any
+///      type implementing `TryInto<T>` and returning `Result<(), E>`
+///      with the conversion error. Cannot be used with
+///      `with_into = true`. Default: false.
+///    - `from_str`: Whether to generate also
+///      `<name>_str(&mut self, &str)` returning `Result<(), E>`, which
+///      parses the value by `FromStr`, e.g. `set_port_str`.
+///      Default: false.
+///
+///      These conversions cannot be used with `validate`, declarative
+///      checks or `const_fn = true`.
+///    - `const_fn`: Whether to generate `const fn`. Cannot be used
+///      with `with_into = true`, so it changes default of `with_into`
+///      to false. Setters of fields of types with destructors, like
+///      `String`, `Vec`, `Box` or `Option`s of them, cannot be const,
+///      since const fn cannot drop previous values. Other types with
+///      destructors are not recognised and fail with error E0493.
+///      Default: macro param or false.
+///    - `strip_option`: Whether setters of `Option<T>` fields take
+///      `T` and wrap it in `Some`. If false, they take `Option<T>`,
+///      so the field can be set to `None`. Only the outer option is
+///      stripped, so setters of `Option<Option<T>>` take `Option<T>`.
+///      Default: macro param or true.
+///    - `option`: Whether the field is an option for `strip_option`.
+///      Fields of `Option`, `std::option::Option` and
+///      `core::option::Option` types are recognised by default. Set
+///      to true for aliases like `type Maybe<T> = Option<T>`, whose
+///      first generic argument is taken as the inner type.
+///    - `option_helpers`: Whether to generate `clear_<suffix>`,
+///      `take_<suffix>` and `<suffix>_get_or_insert_with` methods of
+///      the `Option` field, which set it to `None`, take its value
+///      and get it or insert a value computed by a closure. They have
+///      visibility of the setter. Default: false.
+///    - `replace`: Whether to generate `replace_<suffix>(&mut self,
+///      value)`, which sets the value like the setter does and returns
+///      the previous one. Default: false.
+///    - `take`: Whether to generate `take_<suffix>(&mut self)`, which
+///      takes the value out, leaving `Default::default()` in its
+///      place. Cannot be used with `option_helpers = true`, which
+///      makes the same method of `Option` fields. Default: false.
+///    - `swap`: Whether to generate `swap_<suffix>(&mut self, &mut T)`,
+///      which swaps the value with another one. Default: false.
+///
+///      These methods have visibility of the setter and cannot be
+///      made by const setters.
+///    - `update`: Whether to generate `update_<suffix>(&mut self, f)`,
+///      which changes the value in place by
+///      `f: impl FnOnce(&mut T)`. `f` gets inner values of `Option`
+///      fields and is called only when they are `Some`, unless
+///      `strip_option` is false. Cannot be used with
+///      `const_fn = true`. Default: false.
+///    - `each`: Whether to generate `push_<suffix>(&mut self, item)`
+///      and `extend_<suffix>(&mut self, iter)` methods, which push
+///      items into the sequence field. The setter takes
+///      `impl IntoIterator` of items then. String value names the
+///      push method instead, e.g. "add_item". Items are taken as
+///      `impl Into<T>` like values with `with_into`. Default: false.
+///    - `sequence`: Whether the field is a sequence for `each`. Fields
+///      of `Vec`, `VecDeque` and `LinkedList` types are recognised by
+///      default. Set to true for other collections implementing
+///      `Extend` and `FromIterator`, whose first generic argument is
+///      taken as the item type.
+///    - `singular`: Singular name of entries of map or set field,
+///      e.g. "header", which enables helpers of `HashMap` and
+///      `BTreeMap` fields: `insert_<singular>(&mut self, key, value)`,
+///      `remove_<singular>(&mut self, &K)` and
+///      `contains_<singular>(&self, &K)`, or helpers of `HashSet` and
+///      `BTreeSet` fields: `add_<singular>(&mut self, item)`,
+///      `remove_<singular>(&mut self, &T)` and
+///      `contains_<singular>(&self, &T)`. They return the values of
+///      the underlying methods of the collection. Entries are taken
+///      as `impl Into<T>` like values with `with_into`.
+///    - `validate`: Path of function `fn(&T) -> Result<(), E>`, which
+///      validates values before they are set. Deref coercion applies,
+///      so it can take `&str` for `String` fields. The setter becomes
+///      fallible `try_<prefix>_<suffix>(&mut self, ..)` returning
+///      `Result<(), E>`, which sets the value only if it passes
+///      validation. Cannot be used with `const_fn = true`.
+///    - `error`: Error type of the fallible setter, which errors of
+///      the validator are converted into by `?`. It cannot be inferred
+///      from the validator, so it's required with `validate`.
+///    - `range`: Range expression like "1..=65535", which values must
+///      be contained in.
+///    - `non_empty`: Whether values must not be empty by `is_empty`.
+///    - `min_len`, `max_len`: Bounds of lengths of values by `len`.
+///
+///      These checks make the setter fallible like `validate` does,
+///      but its errors are variants of the generated enum
+///      `<Struct>SetError` with visibility of the struct, e.g.
+///      `FooSetError::PortOutOfRange` for the `port` field. They
+///      cannot be used with `validate`, `error` or `const_fn`.
+///
+///      The enum derives `Debug`, `Clone` and `PartialEq`, so values
+///      checked by `range` must implement them besides `PartialOrd`.
+///      Setters of the same field share variants, so their `range`
+///      params must use the same kind of range, e.g. "1..10" and
+///      "2..5", and the same type of values. The enum has no
+///      generics, so the type cannot use generics of the struct.
+///    - `doc`: Override docs of the method. Default: generated lead
+///      line followed by docs of the field.
 ///
 /// # Example
 /// ```rust
@@ -188,8 +544,29 @@ pub fn make_builder_setters(_: TokenStream, item: TokenStream) -> TokenStream {
 ///
 /// assert_eq!(foo, expected);
 /// ```
+///
+/// # Errors
+/// Invalid params are reported as described in [Errors](crate#errors).
+/// ```compile_fail
+/// use useless_setter_maker::make_basic_setters;
+///
+/// #[make_basic_setters]
+/// struct Foo {
+///     #[basic_setter(prefix = 1)]                // Expected string value
+///     bar: u16,
+///
+///     #[basic_setter(name = "a", suffix = "b")]  // Conflicting params
+///     baz: String,
+/// }
+/// ```
 #[proc_macro_attribute]
-pub fn make_basic_setters(_: TokenStream, item: TokenStream) -> TokenStream {
+pub fn make_basic_setters(
+    params: TokenStream,
+    item: TokenStream,
+) -> TokenStream {
+    let params = parse_macro_input!(
+        params with Punctuated::<MetaNameValue, Token![,]>::parse_terminated
+    );
     let item = parse_macro_input!(item as ItemStruct);
-    do_make_basic_setters(item)
+    do_make_basic_setters(params, item)
 }
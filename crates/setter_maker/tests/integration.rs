@@ -0,0 +1,977 @@
+use useless_setter_maker::{
+    make_basic_setters,
+    make_builder_setters,
+};
+
+#[test]
+fn test_const_fn_basic_setters() {
+    #[make_basic_setters(const_fn = true)]
+    #[derive(Debug, PartialEq)]
+    struct Foo {
+        bar: u16,
+        #[basic_setter(prefix = "install")]
+        baz: Option<char>,
+        #[basic_setter(const_fn = false)]
+        foobar: String,
+    }
+
+    const FOO: Foo = {
+        let mut foo = Foo {
+            bar: 0,
+            baz: None,
+            foobar: String::new(),
+        };
+        foo.set_bar(12);
+        foo.install_baz('x');
+        foo
+    };
+
+    let mut foo = FOO;
+    foo.set_foobar(String::from("asd"));
+
+    let expected = Foo {
+        bar: 12,
+        baz: Some('x'),
+        foobar: String::from("asd"),
+    };
+    assert_eq!(foo, expected);
+}
+
+#[test]
+fn test_const_fn_builder_setters() {
+    #[make_builder_setters]
+    #[derive(Debug, PartialEq)]
+    struct Foo {
+        #[builder_setter(const_fn = true)]
+        bar: u16,
+        #[builder_setter(const_fn = true, with_into = false)]
+        baz: Option<bool>,
+        foobar: String,
+    }
+
+    const FOO: Foo = Foo {
+        bar: 0,
+        baz: None,
+        foobar: String::new(),
+    }
+    .with_bar(12)
+    .with_baz(true);
+
+    let foo = FOO.with_foobar("asd");
+
+    let expected = Foo {
+        bar: 12,
+        baz: Some(true),
+        foobar: String::from("asd"),
+    };
+    assert_eq!(foo, expected);
+}
+
+#[test]
+fn test_struct_defaults_basic_setters() {
+    #[make_basic_setters(
+        prefix = "put",
+        visibility = "pub(crate)",
+        with_into = false
+    )]
+    #[derive(Debug, PartialEq, Default)]
+    struct Foo {
+        bar: u16,
+        #[basic_setter(with_into = true)]
+        baz: String,
+        #[basic_setter(prefix = "set", visibility = "pub")]
+        foobar: Option<u8>,
+    }
+
+    let mut foo = Foo::default();
+    foo.put_bar(12);
+    foo.put_baz("asd");
+    foo.set_foobar(3);
+
+    let expected = Foo {
+        bar: 12,
+        baz: String::from("asd"),
+        foobar: Some(3),
+    };
+    assert_eq!(foo, expected);
+}
+
+#[test]
+fn test_struct_defaults_builder_setters() {
+    #[make_builder_setters(prefix = "set", with_into = false)]
+    #[derive(Debug, PartialEq, Default)]
+    struct Foo {
+        bar: u16,
+        #[builder_setter(with_into = true)]
+        baz: String,
+        #[builder_setter(name = "with_foobar")]
+        foobar: Option<u8>,
+    }
+
+    let foo = Foo::default().set_bar(12).set_baz("asd").with_foobar(3);
+
+    let expected = Foo {
+        bar: 12,
+        baz: String::from("asd"),
+        foobar: Some(3),
+    };
+    assert_eq!(foo, expected);
+}
+
+#[test]
+fn test_tuple_struct_setters() {
+    #[make_basic_setters]
+    #[make_builder_setters]
+    #[derive(Debug, PartialEq, Default)]
+    struct Point(
+        #[basic_setter(name = "set_x")]
+        #[builder_setter(name = "with_x")]
+        f32,
+        #[basic_setter]
+        #[builder_setter]
+        Option<f32>,
+        String,
+    );
+
+    #[make_basic_setters]
+    #[make_builder_setters]
+    struct Marker;
+
+    let mut point = Point::default().with_x(1.5).with_1(2.0);
+    point.set_1(3.0);
+    point.set_x(0.5);
+
+    assert_eq!(point, Point(0.5, Some(3.0), String::new()));
+    let _ = Marker;
+}
+
+#[test]
+fn test_strip_option_basic_setters() {
+    #[make_basic_setters(strip_option = false)]
+    #[derive(Debug, PartialEq, Default)]
+    struct Foo {
+        bar: Option<u16>,
+        #[basic_setter(strip_option = true)]
+        baz: Option<String>,
+        #[basic_setter(strip_option = true)]
+        foobar: Option<Option<u8>>,
+    }
+
+    let mut foo = Foo::default();
+    foo.set_bar(12);
+    foo.set_baz("asd");
+    foo.set_foobar(None);
+    assert_eq!(foo.foobar, Some(None));
+
+    foo.set_bar(None);
+    foo.set_foobar(Some(3));
+
+    let expected = Foo {
+        bar: None,
+        baz: Some(String::from("asd")),
+        foobar: Some(Some(3)),
+    };
+    assert_eq!(foo, expected);
+}
+
+#[test]
+fn test_strip_option_builder_setters() {
+    #[make_builder_setters]
+    #[derive(Debug, PartialEq, Default)]
+    struct Foo {
+        #[builder_setter(strip_option = false, with_into = false)]
+        bar: Option<u16>,
+        baz: Option<Option<u8>>,
+        #[builder_setter(strip_option = false, with_into = false)]
+        foobar: Option<Option<u8>>,
+    }
+
+    let foo = Foo::default()
+        .with_bar(Some(12))
+        .with_bar(None)
+        .with_baz(None)
+        .with_foobar(None);
+
+    let expected = Foo {
+        bar: None,
+        baz: Some(None),
+        foobar: None,
+    };
+    assert_eq!(foo, expected);
+}
+
+#[test]
+fn test_option_paths() {
+    mod custom {
+        #[derive(Debug, PartialEq, Default)]
+        pub struct Option<T>(pub T);
+    }
+
+    type Maybe<T> = Option<T>;
+
+    #[make_basic_setters]
+    #[make_builder_setters]
+    #[derive(Debug, PartialEq, Default)]
+    struct Foo {
+        bar: std::option::Option<u16>,
+        baz: ::core::option::Option<char>,
+        foobar: custom::Option<u8>,
+        #[basic_setter(option = true)]
+        #[builder_setter(option = true)]
+        barbaz: Maybe<bool>,
+    }
+
+    let mut foo = Foo::default()
+        .with_bar(1_u16)
+        .with_baz('x')
+        .with_foobar(custom::Option(2))
+        .with_barbaz(true);
+    foo.set_bar(12_u16);
+    foo.set_foobar(custom::Option(3));
+
+    let expected = Foo {
+        bar: Some(12),
+        baz: Some('x'),
+        foobar: custom::Option(3),
+        barbaz: Some(true),
+    };
+    assert_eq!(foo, expected);
+}
+
+#[test]
+fn test_option_helpers() {
+    #[make_basic_setters]
+    #[make_builder_setters]
+    #[derive(Debug, PartialEq, Default)]
+    struct Foo {
+        #[basic_setter(option_helpers = true)]
+        #[builder_setter(option_helpers = true)]
+        bar: Option<u16>,
+        #[basic_setter(suffix = "value", option_helpers = true)]
+        #[builder_setter(suffix = "value", option_helpers = true)]
+        baz: Option<String>,
+    }
+
+    let mut foo = Foo::default().with_bar(1_u16).with_value("asd");
+    assert_eq!(foo.take_bar(), Some(1));
+    assert_eq!(foo.take_bar(), None);
+
+    foo.bar_get_or_insert_with(|| 2);
+    *foo.bar_get_or_insert_with(|| 3) += 1;
+    assert_eq!(foo.bar, Some(3));
+
+    foo.clear_bar();
+    foo.value_get_or_insert_with(String::new).push('!');
+    assert_eq!(foo.bar, None);
+    assert_eq!(foo.baz, Some(String::from("asd!")));
+
+    foo.clear_value();
+    assert_eq!(foo, Foo::default());
+
+    let foo = Foo::default()
+        .with_bar(1_u16)
+        .without_bar()
+        .with_value("asd");
+    assert_eq!(foo.without_value(), Foo::default());
+}
+
+mod validators {
+    #[derive(Debug, PartialEq)]
+    pub struct InvalidPort(pub u16);
+
+    pub fn validate_port(port: &u16) -> Result<(), InvalidPort> {
+        match port {
+            0 => Err(InvalidPort(*port)),
+            _ => Ok(()),
+        }
+    }
+
+    pub fn validate_host(host: &str) -> Result<(), &'static str> {
+        match host.is_empty() {
+            true => Err("Host cannot be empty."),
+            false => Ok(()),
+        }
+    }
+}
+
+#[test]
+fn test_validated_basic_setters() {
+    use validators::InvalidPort;
+
+    #[make_basic_setters]
+    #[derive(Debug, PartialEq, Default)]
+    struct Config {
+        #[basic_setter(
+            validate = "validators::validate_port",
+            error = "InvalidPort"
+        )]
+        port: u16,
+        #[basic_setter(
+            validate = "validators::validate_host",
+            error = "&'static str"
+        )]
+        host: Option<String>,
+    }
+
+    let mut config = Config::default();
+    assert_eq!(config.try_set_port(8080_u16), Ok(()));
+    assert_eq!(config.try_set_port(0_u16), Err(InvalidPort(0)));
+    assert!(config.try_set_host("").is_err());
+    assert!(config.try_set_host("localhost").is_ok());
+
+    let expected = Config {
+        port: 8080,
+        host: Some(String::from("localhost")),
+    };
+    assert_eq!(config, expected);
+}
+
+#[test]
+fn test_validated_builder_setters() {
+    use validators::InvalidPort;
+
+    #[make_builder_setters]
+    #[derive(Debug, PartialEq, Default)]
+    struct Config {
+        #[builder_setter(
+            validate = "validators::validate_port",
+            error = "InvalidPort",
+            with_into = false
+        )]
+        port: u16,
+        #[builder_setter(
+            name = "host",
+            validate = "validators::validate_host",
+            error = "&'static str"
+        )]
+        host: String,
+    }
+
+    let config = Config::default()
+        .try_with_port(8080)
+        .unwrap()
+        .host("localhost")
+        .unwrap();
+    assert_eq!(config.port, 8080);
+    assert_eq!(config.host, "localhost");
+
+    assert_eq!(
+        Config::default().try_with_port(0).unwrap_err(),
+        InvalidPort(0)
+    );
+    let error = Config::default().host("").unwrap_err();
+    assert_eq!(error.to_string(), "Host cannot be empty.");
+}
+
+#[test]
+fn test_checked_basic_setters() {
+    #[make_basic_setters]
+    #[derive(Debug, PartialEq, Default)]
+    struct Config {
+        #[basic_setter(range = "1..=65535", with_into = false)]
+        port: u32,
+        #[basic_setter(non_empty = true, max_len = 8)]
+        host: String,
+        #[basic_setter(min_len = 2)]
+        tags: Option<Vec<u8>>,
+    }
+
+    let mut config = Config::default();
+    config.try_set_port(8080).unwrap();
+    config.try_set_host("local").unwrap();
+    config.try_set_tags(vec![1, 2]).unwrap();
+    assert_eq!(config.port, 8080);
+    assert_eq!(config.host, "local");
+    assert_eq!(config.tags, Some(vec![1, 2]));
+
+    assert_eq!(
+        config.try_set_port(70000),
+        Err(ConfigSetError::PortOutOfRange {
+            value: 70000,
+            range: 1..=65535,
+        })
+    );
+    assert_eq!(config.try_set_host(""), Err(ConfigSetError::HostEmpty));
+    assert_eq!(
+        config.try_set_host("localhost"),
+        Err(ConfigSetError::HostTooLong { len: 9, max_len: 8 })
+    );
+    assert_eq!(
+        config.try_set_tags(vec![1]),
+        Err(ConfigSetError::TagsTooShort { len: 1, min_len: 2 })
+    );
+    assert_eq!(config.port, 8080);
+    assert_eq!(config.host, "local");
+    assert_eq!(config.tags, Some(vec![1, 2]));
+
+    let error: Box<dyn std::error::Error> =
+        Box::new(config.try_set_port(0).unwrap_err());
+    assert_eq!(
+        error.to_string(),
+        "Value 0 of `port` is out of range 1..=65535."
+    );
+    assert_eq!(
+        ConfigSetError::HostEmpty.to_string(),
+        "`host` cannot be empty."
+    );
+}
+
+#[test]
+fn test_checked_builder_setters() {
+    #[make_builder_setters]
+    #[derive(Debug, PartialEq, Default)]
+    struct Config {
+        #[builder_setter(range = "1..", with_into = false)]
+        port: u16,
+        #[builder_setter(name = "name", min_len = 3, max_len = 5)]
+        user_name: String,
+    }
+
+    let config = Config::default()
+        .try_with_port(80)
+        .unwrap()
+        .name("admin")
+        .unwrap();
+    assert_eq!(config.port, 80);
+    assert_eq!(config.user_name, "admin");
+
+    assert_eq!(
+        Config::default().try_with_port(0),
+        Err(ConfigWithError::PortOutOfRange {
+            value: 0,
+            range: 1..,
+        })
+    );
+    let error = Config::default().name("root_admin").unwrap_err();
+    assert_eq!(
+        error,
+        ConfigWithError::UserNameTooLong {
+            len: 10,
+            max_len: 5,
+        }
+    );
+    assert_eq!(
+        error.to_string(),
+        "Length 10 of `user_name` is greater than 5."
+    );
+    assert_eq!(
+        Config::default().name("me").unwrap_err().to_string(),
+        "Length 2 of `user_name` is less than 3."
+    );
+}
+
+#[test]
+fn test_checked_setters_of_generic_struct() {
+    #[make_basic_setters]
+    #[derive(Debug, PartialEq, Default)]
+    struct Slot<T> {
+        #[basic_setter(range = "1..=8", with_into = false)]
+        index: u8,
+        #[basic_setter(non_empty = true)]
+        items: Vec<T>,
+    }
+
+    let mut slot: Slot<char> = Slot::default();
+    slot.try_set_index(3).unwrap();
+    slot.try_set_items(vec!['a']).unwrap();
+    assert_eq!(slot.index, 3);
+    assert_eq!(slot.items, vec!['a']);
+
+    assert_eq!(
+        slot.try_set_index(9),
+        Err(SlotSetError::IndexOutOfRange {
+            value: 9,
+            range: 1..=8,
+        })
+    );
+    assert_eq!(
+        slot.try_set_items(Vec::new()),
+        Err(SlotSetError::ItemsEmpty)
+    );
+}
+
+#[test]
+fn test_conversion_basic_setters() {
+    use std::num::{
+        IntErrorKind,
+        TryFromIntError,
+    };
+
+    #[make_basic_setters]
+    #[derive(Debug, PartialEq, Default)]
+    struct Config {
+        #[basic_setter(with_try_into = true, from_str = true)]
+        port: u16,
+        #[basic_setter(from_str = true)]
+        timeout: Option<f64>,
+    }
+
+    let mut config = Config::default();
+    config.try_set_port(8080_i64).unwrap();
+    assert_eq!(config.port, 8080);
+    let error: TryFromIntError = config.try_set_port(-1_i32).unwrap_err();
+    assert_eq!(error, u16::try_from(-1_i32).unwrap_err());
+    assert_eq!(config.port, 8080);
+
+    config.try_set_port_str("443").unwrap();
+    assert_eq!(config.port, 443);
+    let error = config.try_set_port_str("70000").unwrap_err();
+    assert_eq!(error.kind(), &IntErrorKind::PosOverflow);
+    assert_eq!(config.port, 443);
+
+    config.set_timeout_str("1.5").unwrap();
+    assert_eq!(config.timeout, Some(1.5));
+    assert!(config.set_timeout_str("soon").is_err());
+    config.set_timeout(2.0);
+    assert_eq!(config.timeout, Some(2.0));
+}
+
+#[test]
+fn test_conversion_builder_setters() {
+    use std::net::Ipv4Addr;
+
+    #[make_builder_setters]
+    #[derive(Debug, PartialEq, Default)]
+    struct Config {
+        #[builder_setter(name = "port", with_try_into = true)]
+        port: u16,
+        #[builder_setter(from_str = true)]
+        address: Option<Ipv4Addr>,
+    }
+
+    let config = Config::default()
+        .port(8080_u64)
+        .unwrap()
+        .with_address_str("127.0.0.1")
+        .unwrap();
+    assert_eq!(config.port, 8080);
+    assert_eq!(config.address, Some(Ipv4Addr::LOCALHOST));
+
+    assert!(Config::default().port(u64::MAX).is_err());
+    assert!(Config::default().with_address_str("localhost").is_err());
+    let config = Config::default().with_address(Ipv4Addr::UNSPECIFIED);
+    assert_eq!(config.address, Some(Ipv4Addr::UNSPECIFIED));
+}
+
+#[test]
+fn test_sequence_basic_setters() {
+    use std::collections::{
+        BTreeSet,
+        VecDeque,
+    };
+
+    #[make_basic_setters]
+    #[derive(Debug, PartialEq, Default)]
+    struct Playlist {
+        #[basic_setter(each = true)]
+        tags: Vec<String>,
+        #[basic_setter(each = "enqueue", with_into = false)]
+        queue: std::collections::VecDeque<u32>,
+        #[basic_setter(each = "add_artist", sequence = true)]
+        artists: BTreeSet<String>,
+    }
+
+    let mut playlist = Playlist::default();
+    playlist.set_tags(["rock", "live"]);
+    playlist.push_tags("loud");
+    playlist.extend_tags(vec![String::from("old")]);
+    assert_eq!(playlist.tags, ["rock", "live", "loud", "old"]);
+
+    playlist.set_queue(vec![3, 1]);
+    playlist.enqueue(2);
+    playlist.extend_queue(4..6);
+    assert_eq!(playlist.queue, VecDeque::from([3, 1, 2, 4, 5]));
+
+    playlist.add_artist("b");
+    playlist.extend_artists(["a", "b"]);
+    assert_eq!(playlist.artists, BTreeSet::from(["a".into(), "b".into()]));
+    playlist.set_artists(["c"]);
+    assert_eq!(playlist.artists, BTreeSet::from(["c".into()]));
+}
+
+#[test]
+fn test_sequence_builder_setters() {
+    #[make_builder_setters]
+    #[derive(Debug, PartialEq, Default)]
+    struct Request {
+        #[builder_setter(each = "arg")]
+        args: Vec<String>,
+        #[builder_setter(each = true, suffix = "port")]
+        ports: Vec<u16>,
+    }
+
+    let request = Request::default()
+        .with_args(["-v"])
+        .arg("--all")
+        .with_args_items(["a", "b"])
+        .with_port([80_u16])
+        .with_port_item(443_u16)
+        .with_port_items([8080_u16]);
+    assert_eq!(request.args, ["-v", "--all", "a", "b"]);
+    assert_eq!(request.ports, [80, 443, 8080]);
+}
+
+#[test]
+fn test_keyed_collection_basic_setters() {
+    use std::collections::{
+        BTreeSet,
+        HashMap,
+    };
+
+    #[make_basic_setters]
+    #[derive(Debug, PartialEq, Default)]
+    struct Request {
+        #[basic_setter(singular = "header")]
+        headers: HashMap<String, String>,
+        #[basic_setter(singular = "flag", with_into = false)]
+        flags: BTreeSet<u8>,
+    }
+
+    let mut request = Request::default();
+    assert_eq!(request.insert_header("Accept", "text/html"), None);
+    assert_eq!(
+        request.insert_header("Accept", "*/*"),
+        Some(String::from("text/html"))
+    );
+    assert!(request.contains_header(&String::from("Accept")));
+    assert_eq!(
+        request.remove_header(&String::from("Accept")),
+        Some(String::from("*/*"))
+    );
+    assert!(!request.contains_header(&String::from("Accept")));
+    request.set_headers(HashMap::from([("Host".into(), "a".into())]));
+    assert_eq!(request.headers.len(), 1);
+
+    assert!(request.add_flag(3));
+    assert!(!request.add_flag(3));
+    assert!(request.add_flag(1));
+    assert!(request.contains_flag(&1));
+    assert!(request.remove_flag(&1));
+    assert!(!request.remove_flag(&1));
+    assert_eq!(request.flags, BTreeSet::from([3]));
+}
+
+#[test]
+fn test_keyed_collection_builder_setters() {
+    use std::collections::{
+        BTreeMap,
+        HashSet,
+    };
+
+    #[make_builder_setters]
+    #[derive(Debug, PartialEq, Default)]
+    struct Request {
+        #[builder_setter(singular = "header")]
+        headers: BTreeMap<String, String>,
+        #[builder_setter(singular = "tag")]
+        tags: HashSet<String>,
+    }
+
+    let request = Request::default()
+        .with_header("Accept", "*/*")
+        .with_header("Host", "localhost")
+        .with_tag("a")
+        .with_tag("a");
+    assert_eq!(
+        request.headers,
+        BTreeMap::from([
+            ("Accept".into(), "*/*".into()),
+            ("Host".into(), "localhost".into()),
+        ])
+    );
+    assert_eq!(request.tags, HashSet::from(["a".into()]));
+}
+
+#[test]
+fn test_mem_basic_setters() {
+    #[make_basic_setters]
+    #[derive(Debug, PartialEq, Default)]
+    struct Machine {
+        #[basic_setter(replace = true, take = true, swap = true)]
+        state: String,
+        #[basic_setter(replace = true, visibility = "pub(crate)")]
+        ticket: Option<u64>,
+        #[basic_setter(swap = true, suffix = "buffer")]
+        pending: Vec<u8>,
+    }
+
+    let mut machine = Machine::default();
+    machine.set_state("idle");
+    assert_eq!(machine.replace_state("running"), "idle");
+    assert_eq!(machine.take_state(), "running");
+    assert_eq!(machine.state, "");
+    let mut other = String::from("done");
+    machine.swap_state(&mut other);
+    assert_eq!(machine.state, "done");
+    assert_eq!(other, "");
+
+    assert_eq!(machine.replace_ticket(1_u64), None);
+    assert_eq!(machine.replace_ticket(2_u64), Some(1));
+    assert_eq!(machine.ticket, Some(2));
+
+    let mut buffer = vec![1, 2];
+    machine.swap_buffer(&mut buffer);
+    assert_eq!(machine.pending, [1, 2]);
+    assert!(buffer.is_empty());
+}
+
+#[test]
+fn test_ref_mut_builder_setters() {
+    use std::collections::HashMap;
+
+    #[make_builder_setters(receiver = "ref_mut")]
+    #[derive(Debug, PartialEq, Default)]
+    struct Config {
+        port: u16,
+        #[builder_setter(from_str = true)]
+        timeout: Option<u32>,
+        #[builder_setter(option_helpers = true)]
+        user: Option<String>,
+        #[builder_setter(each = true)]
+        hosts: Vec<String>,
+        #[builder_setter(singular = "label")]
+        labels: HashMap<String, String>,
+        #[builder_setter(receiver = "owned")]
+        name: String,
+    }
+
+    fn configure(config: &mut Config) {
+        config
+            .with_port(8080_u16)
+            .with_user("root")
+            .without_user()
+            .with_hosts_item("a")
+            .with_hosts_items(["b"])
+            .with_label("env", "prod");
+        config.with_timeout_str("30").unwrap().with_port(80_u16);
+    }
+
+    let mut config = Config::default();
+    configure(&mut config);
+    assert_eq!(config.port, 80);
+    assert_eq!(config.timeout, Some(30));
+    assert_eq!(config.user, None);
+    assert_eq!(config.hosts, ["a", "b"]);
+    assert_eq!(config.labels["env"], "prod");
+
+    let config = config.with_name("owned");
+    assert_eq!(config.name, "owned");
+}
+
+#[test]
+fn test_update_basic_setters() {
+    #[make_basic_setters]
+    #[derive(Debug, PartialEq, Default)]
+    struct Counter {
+        #[basic_setter(update = true)]
+        count: u32,
+        #[basic_setter(update = true)]
+        label: Option<String>,
+        #[basic_setter(update = true, strip_option = false)]
+        limit: Option<u32>,
+    }
+
+    let mut counter = Counter::default();
+    counter.update_count(|count| *count += 2);
+    assert_eq!(counter.count, 2);
+
+    counter.update_label(|_| panic!("Label is `None`."));
+    counter.set_label("a");
+    counter.update_label(|label| label.push('b'));
+    assert_eq!(counter.label.as_deref(), Some("ab"));
+
+    counter.update_limit(|limit| *limit = Some(10));
+    assert_eq!(counter.limit, Some(10));
+}
+
+#[test]
+fn test_map_builder_setters() {
+    #[make_builder_setters]
+    #[derive(Debug, PartialEq, Default)]
+    struct Counter {
+        #[builder_setter(update = true)]
+        count: u32,
+        #[builder_setter(update = true)]
+        label: Option<String>,
+        #[builder_setter(update = true, receiver = "ref_mut")]
+        tags: Vec<String>,
+    }
+
+    let counter = Counter::default()
+        .map_label(|_| panic!("Label is `None`."))
+        .with_count(3_u32)
+        .map_count(|count| count * 2)
+        .with_label("a")
+        .map_label(|label| label + "b");
+    assert_eq!(counter.count, 6);
+    assert_eq!(counter.label.as_deref(), Some("ab"));
+
+    let mut counter = counter;
+    counter
+        .map_tags(|tags| tags.push("x".into()))
+        .map_tags(|tags| tags.push("y".into()));
+    assert_eq!(counter.tags, ["x", "y"]);
+}
+
+#[test]
+fn test_type_changing_builder_setters() {
+    use std::fmt::Debug;
+
+    #[make_builder_setters]
+    #[derive(Debug, PartialEq)]
+    struct Request<'a, T: Debug, B, const N: usize>
+    where
+        T: Clone,
+    {
+        path: &'a str,
+        #[builder_setter(change_type = true)]
+        body: B,
+        #[builder_setter(change_type = true, name = "with_marker")]
+        marker: T,
+        retries: [u8; N],
+    }
+
+    let request = Request {
+        path: "/",
+        body: [(); 0],
+        marker: (),
+        retries: [1, 2],
+    }
+    .with_path("/items")
+    .with_marker(7_u8)
+    .with_body(vec![1_u8, 2]);
+    assert_eq!(
+        request,
+        Request {
+            path: "/items",
+            body: vec![1, 2],
+            marker: 7,
+            retries: [1, 2],
+        }
+    );
+
+    #[make_builder_setters]
+    #[derive(Debug, PartialEq)]
+    struct Pair<U>(#[builder_setter(change_type = true)] U, u8);
+
+    let pair = Pair((), 1).with_0("a").with_0(2_i64);
+    assert_eq!(pair, Pair(2, 1));
+}
+
+#[test]
+fn test_typestate_builder() {
+    #[make_builder_setters(typestate = true)]
+    #[derive(Debug, PartialEq)]
+    struct Server<'a, T>
+    where
+        T: Clone,
+    {
+        #[required]
+        host: &'a str,
+        #[required]
+        #[builder_setter(name = "port", with_into = false)]
+        port: u16,
+        #[required]
+        handler: T,
+        timeout: Option<u32>,
+        #[disable_builder_setters]
+        retries: u8,
+    }
+
+    let server = Server::builder()
+        .with_timeout(30_u32)
+        .port(8080)
+        .with_handler(vec![1])
+        .with_host("localhost")
+        .build();
+    assert_eq!(
+        server,
+        Server {
+            host: "localhost",
+            port: 8080,
+            handler: vec![1],
+            timeout: Some(30),
+            retries: 0,
+        }
+    );
+
+    let builder: ServerBuilder<()> = Server::builder();
+    let server = builder
+        .with_host("example.com")
+        .port(80)
+        .with_handler(())
+        .build();
+    assert_eq!(server.timeout, None);
+
+    #[make_builder_setters(typestate = true)]
+    #[derive(Debug, PartialEq)]
+    struct Point(
+        #[required]
+        #[builder_setter(name = "x")]
+        i32,
+        #[builder_setter(name = "y")] i32,
+    );
+
+    assert_eq!(Point::builder().x(1).build(), Point(1, 0));
+    assert_eq!(Point::builder().y(2).x(1).build(), Point(1, 2));
+}
+
+/// Fails to compile if any generated setter is left undocumented.
+#[deny(missing_docs)]
+pub mod documented_setters {
+    use useless_setter_maker::{
+        make_basic_setters,
+        make_builder_setters,
+    };
+
+    /// Struct with documented setters.
+    #[make_basic_setters]
+    #[make_builder_setters]
+    pub struct Config {
+        /// Port to listen on.
+        ///
+        /// Must not be zero.
+        port: u16,
+        #[basic_setter(doc = "Sets host name.")]
+        #[builder_setter(doc = "Returns `self` with host name set.")]
+        host: Option<String>,
+        /// Number of worker threads.
+        #[basic_setter(range = "1..=64")]
+        #[builder_setter(range = "1..=64")]
+        workers: u8,
+        /// Name of the service.
+        #[basic_setter(non_empty = true, max_len = 16)]
+        #[builder_setter(min_len = 1)]
+        name: String,
+        /// Timeout in seconds.
+        #[basic_setter(with_try_into = true, from_str = true)]
+        #[builder_setter(with_try_into = true, from_str = true)]
+        timeout: u32,
+        /// Names of the service.
+        #[basic_setter(each = true)]
+        #[builder_setter(each = "alias")]
+        aliases: Vec<String>,
+        /// Labels of the service.
+        #[basic_setter(
+            singular = "label",
+            replace = true,
+            swap = true,
+            update = true
+        )]
+        #[builder_setter(singular = "label", update = true)]
+        labels: std::collections::HashMap<String, String>,
+    }
+
+    /// Struct with documented typestate builder.
+    #[make_builder_setters(typestate = true)]
+    pub struct Endpoint<T> {
+        /// Address of the endpoint.
+        #[required]
+        pub address: String,
+        /// Handler of requests.
+        #[required]
+        pub handler: T,
+        /// Timeout in seconds.
+        pub timeout: Option<u32>,
+    }
+}
@@ -0,0 +1,11 @@
+use useless_setter_maker::make_basic_setters;
+
+#[make_basic_setters]
+struct Foo {
+    #[basic_setter(non_empty = true)]
+    foo_bar: String,
+    #[basic_setter(non_empty = true)]
+    foo__bar: String,
+}
+
+fn main() {}
@@ -0,0 +1,18 @@
+use useless_setter_maker::{
+    make_basic_setters,
+    make_builder_setters,
+};
+
+#[make_builder_setters]
+struct Foo {
+    #[builder_setter(name = "with_a", suffix = "b")]
+    bar: u16,
+}
+
+#[make_basic_setters]
+struct Baz {
+    #[basic_setter(option_helpers = true, take = true)]
+    qux: Option<u16>,
+}
+
+fn main() {}
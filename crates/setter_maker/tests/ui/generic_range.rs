@@ -0,0 +1,9 @@
+use useless_setter_maker::make_basic_setters;
+
+#[make_basic_setters]
+struct Foo<T: From<u8>> {
+    #[basic_setter(range = "T::from(1)..T::from(9)")]
+    bar: T,
+}
+
+fn main() {}
@@ -0,0 +1,17 @@
+use useless_setter_maker::{
+    make_basic_setters,
+    make_builder_setters,
+};
+
+#[make_builder_setters(visibility = "public")]
+struct Foo {
+    bar: u16,
+}
+
+#[make_basic_setters]
+struct Baz {
+    #[basic_setter(visibility = "pub(in)")]
+    qux: u16,
+}
+
+fn main() {}
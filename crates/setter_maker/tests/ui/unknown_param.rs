@@ -0,0 +1,18 @@
+use useless_setter_maker::{
+    make_basic_setters,
+    make_builder_setters,
+};
+
+#[make_builder_setters(prefixx = "set")]
+struct Foo {
+    #[builder_setter(sufix = "a")]
+    bar: u16,
+}
+
+#[make_basic_setters]
+struct Baz {
+    #[basic_setter(receiver = "ref_mut")]
+    qux: u16,
+}
+
+fn main() {}
@@ -0,0 +1,16 @@
+use useless_setter_maker::make_basic_setters;
+
+fn validate_port(port: &u16) -> Result<(), String> {
+    match port {
+        0 => Err(String::from("Port cannot be zero.")),
+        _ => Ok(()),
+    }
+}
+
+#[make_basic_setters]
+struct Foo {
+    #[basic_setter(validate = "validate_port")]
+    port: u16,
+}
+
+fn main() {}